@@ -0,0 +1,90 @@
+//! Expansion preview of roopert attributes, without `cargo expand` or a nightly compiler
+
+use proc_macro2::TokenStream;
+
+use syn::{Attribute, File, Item};
+
+use quote::ToTokens;
+
+use roopert_macro_common::{RoopertAttribute, Generate};
+use roopert_macro_common::parse::is_roopert_attribute;
+
+/// Expands every item-level `#[roopert(...)]` attribute in the Rust source code.
+/// Attributes are expanded from top to bottom like the compiler does,
+/// so the generated code is the same as the code generated by the `roopert` attribute macro.
+pub fn expand_source(source: &str) -> Result<TokenStream, String> {
+    let file: File = syn::parse_str(source).map_err(|e| format!("Cannot parse source file: {}", e))?;
+    let mut items = Vec::with_capacity(file.items.len());
+    for item in file.items {
+        items.extend(expand_item(item)?);
+    }
+    let expanded = File {
+        shebang: file.shebang,
+        attrs: file.attrs,
+        items,
+    };
+    Ok(expanded.to_token_stream())
+}
+
+/// Formats the code with `rustfmt`, or returns `None` if `rustfmt` is not available or fails.
+pub fn format(code: &TokenStream) -> Option<String> {
+    use std::io::Write;
+    use std::process::{Command, Stdio};
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--emit", "stdout", "--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .ok()?;
+    rustfmt.stdin.take()?.write_all(code.to_string().as_bytes()).ok()?;
+    let output = rustfmt.wait_with_output().ok()?;
+    if output.status.success() {
+        String::from_utf8(output.stdout).ok()
+    } else {
+        None
+    }
+}
+
+fn expand_item(mut item: Item) -> Result<Vec<Item>, String> {
+    if let Item::Mod(module) = &mut item {
+        if let Some((_, content)) = module.content.take() {
+            let mut items = Vec::with_capacity(content.len());
+            for inner in content {
+                items.extend(expand_item(inner)?);
+            }
+            module.content = Some((Default::default(), items));
+        }
+        return Ok(vec![item]);
+    }
+    let attr = match item_attrs(&mut item) {
+        Some(attrs) => match attrs.iter().position(is_roopert_attribute) {
+            Some(index) => attrs.remove(index),
+            None => return Ok(vec![item]),
+        },
+        None => return Ok(vec![item]),
+    };
+    let mut roopert: RoopertAttribute = attr.parse_args()
+        .map_err(|e| format!("Cannot parse {}: {}", attr.to_token_stream(), e))?;
+    let generated = roopert.generate_auto(item.to_token_stream())
+        .map_err(|e| format!("Cannot expand {}: {}", attr.to_token_stream(), e))?;
+    let file: File = syn::parse2(generated).map_err(|e| format!("Cannot parse code generated by {}: {}", attr.to_token_stream(), e))?;
+    // the generated items may have more roopert attributes
+    let mut items = Vec::with_capacity(file.items.len());
+    for generated_item in file.items {
+        items.extend(expand_item(generated_item)?);
+    }
+    Ok(items)
+}
+
+fn item_attrs(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+    match item {
+        Item::Struct(item) => Some(&mut item.attrs),
+        Item::Enum(item) => Some(&mut item.attrs),
+        Item::Union(item) => Some(&mut item.attrs),
+        Item::Trait(item) => Some(&mut item.attrs),
+        Item::Impl(item) => Some(&mut item.attrs),
+        Item::Fn(item) => Some(&mut item.attrs),
+        _ => None,
+    }
+}
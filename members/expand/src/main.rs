@@ -0,0 +1,40 @@
+//! Prints the code generated by the roopert attributes of a Rust source file.
+//! Usage: `roopert-expand <file.rs>`, or `roopert-expand -` to read from stdin.
+
+use std::io::Read;
+
+fn main() {
+    let path = match std::env::args().nth(1) {
+        Some(path) => path,
+        None => {
+            eprintln!("Usage: roopert-expand <file.rs>");
+            std::process::exit(2);
+        }
+    };
+    let source = if path == "-" {
+        let mut source = String::new();
+        std::io::stdin().read_to_string(&mut source).map(|_| source)
+    } else {
+        std::fs::read_to_string(&path)
+    };
+    let source = match source {
+        Ok(source) => source,
+        Err(e) => {
+            eprintln!("Cannot read {}: {}", path, e);
+            std::process::exit(1);
+        }
+    };
+    match roopert_expand::expand_source(&source) {
+        Ok(expanded) => match roopert_expand::format(&expanded) {
+            Some(formatted) => print!("{}", formatted),
+            None => {
+                eprintln!("rustfmt is not available, printing unformatted code");
+                println!("{}", expanded);
+            }
+        },
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(1);
+        }
+    }
+}
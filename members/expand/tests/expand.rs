@@ -0,0 +1,57 @@
+const SOURCE: &str = r#"
+use roopert::roopert;
+
+#[roopert(accessors, get = all)]
+struct Point {
+    #[roopert(set)]
+    x: i32,
+    y: i32,
+}
+
+mod shapes {
+    #[roopert(builder)]
+    #[roopert(extends, super::Point)]
+    pub struct Circle {
+        center: super::Point,
+        radius: u32,
+    }
+}
+"#;
+
+#[test]
+fn expand_accessors_test() {
+    let expanded = roopert_expand::expand_source(SOURCE).unwrap().to_string();
+    assert!(!expanded.contains("# [roopert"));
+    assert!(expanded.contains("fn get_x"));
+    assert!(expanded.contains("fn set_x"));
+    assert!(expanded.contains("fn get_y"));
+    assert!(!expanded.contains("fn set_y"));
+}
+
+#[test]
+fn expand_nested_test() {
+    let expanded = roopert_expand::expand_source(SOURCE).unwrap().to_string();
+    assert!(expanded.contains("mod shapes"));
+    assert!(expanded.contains("Upcast < super :: Point > for Circle"));
+    assert!(expanded.contains("CircleBuilder"));
+}
+
+#[test]
+fn expand_error_test() {
+    let err = roopert_expand::expand_source("#[roopert(not_an_attribute)] struct Bad;").unwrap_err();
+    assert!(err.contains("not_an_attribute"), "{}", err);
+    assert!(roopert_expand::expand_source("struct").is_err());
+}
+
+#[test]
+fn expand_binary_test() {
+    let path = std::env::temp_dir().join("roopert_expand_binary_test.rs");
+    std::fs::write(&path, SOURCE).unwrap();
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_roopert-expand"))
+        .arg(&path)
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    assert!(stdout.contains("fn get_x"));
+}
@@ -0,0 +1,76 @@
+//! Roopert re-exported from a facade module, to check that generated code honours the `crate = "..."` option
+
+use serde::Deserialize;
+
+/// Facade which re-exports roopert
+pub mod facade {
+    pub use renamed_roopert as roopert;
+}
+
+use facade::roopert::roopert;
+
+/// Shared state of every shape
+#[roopert(crate = "crate::facade::roopert")]
+#[roopert(reflect)]
+#[roopert(properties)]
+#[roopert(builder)]
+#[roopert(extends)]
+#[roopert(accessors, get = all, set = all, serde)]
+#[derive(Default, Deserialize)]
+pub struct Shape {
+    #[roopert(set, post = self.revision += 1)]
+    name: String,
+    #[roopert(skip)]
+    #[serde(skip)]
+    #[builder(default)]
+    pub revision: u32,
+}
+
+/// Abstract methods of every shape
+#[roopert(crate = "crate::facade::roopert", abstract, Shape)]
+pub trait ShapeMethods {
+    /// Number of sides of the shape
+    fn sides(&self) -> u32;
+}
+
+/// Shapes as trait objects
+#[roopert(object_trait, base = Shape)]
+#[roopert(crate = "crate::facade::roopert")]
+pub trait AnyShape {}
+
+/// Area of a shape
+#[roopert(crate = "crate::facade::roopert", delegatable)]
+pub trait Area {
+    /// Area of the shape
+    fn area(&self) -> u32;
+}
+
+/// Shape with four sides
+#[roopert(crate = "crate::facade::roopert")]
+#[roopert(reflect, parents = nested)]
+#[roopert(new)]
+#[roopert(extends, Shape)]
+pub struct Square {
+    base: Shape,
+    side: u32,
+}
+
+impl ShapeMethods for Square {
+    fn sides(&self) -> u32 {
+        4
+    }
+}
+
+impl Area for Square {
+    fn area(&self) -> u32 {
+        self.side * self.side
+    }
+}
+
+impl AnyShape for Square {}
+
+/// Anything with an area
+#[roopert(crate = "crate::facade::roopert", delegate(Area => inner))]
+pub struct AreaOf<T: Area> {
+    pub inner: T,
+}
@@ -0,0 +1,22 @@
+//! Generated code with a crate path
+
+use renamed_roopert::{HierarchyExt, Properties, Reflect};
+use roopert_facade_check::{Area, AreaOf, AnyShape, Shape, ShapeMethods, Square};
+
+#[test]
+fn facade_test() {
+    let base = Shape::builder().name("square".to_string()).build().unwrap();
+    let mut square = Square::new(base, 3);
+    assert_eq!(square.sides(), 4);
+    assert!(square.is_a::<Shape>());
+    square.set_property("name", "renamed").unwrap();
+    assert_eq!(square.get_property("name").unwrap(), "renamed");
+    assert_eq!(square.get_field("base.name").unwrap().downcast_ref::<String>().unwrap(), "renamed");
+    let loaded: Shape = serde_json::from_str(r#"{"name":"loaded"}"#).unwrap();
+    assert_eq!(loaded.get_name(), "loaded");
+    assert_eq!(loaded.get_field("revision").unwrap().downcast_ref::<u32>(), Some(&1));
+    let boxed: Box<dyn AnyShape> = Box::new(square);
+    assert_eq!(boxed.as_base().get_name(), "renamed");
+    let square = boxed.downcast::<Square>().ok().unwrap();
+    assert_eq!(AreaOf { inner: *square }.area(), 9);
+}
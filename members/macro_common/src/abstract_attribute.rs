@@ -0,0 +1,67 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemTrait, Result, Type, TypeParamBound, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, ToTokens};
+
+use super::{Generate, CratePath};
+
+/// Abstract methods of a base type, from a `#[roopert(abstract, ...)]` attribute on a trait
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct AbstractAttribute {
+    base: Type,
+    crate_path: CratePath,
+}
+
+impl AbstractAttribute {
+    /// Abstract methods of the base type
+    pub fn new(base: Type) -> Self {
+        Self {
+            base,
+            crate_path: CratePath::default(),
+        }
+    }
+}
+
+impl Parse for AbstractAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Self {
+            base: input.parse().map_err(|e| Error::new(e.span(), format!("Expected base type in #[roopert(abstract, ...)]: {}", e)))?,
+            crate_path: CratePath::default(),
+        })
+    }
+}
+
+impl Generate for AbstractAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let mut target_trait: ItemTrait = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only traits can declare roopert abstract methods"))?;
+        let base_type = &self.base;
+        let roopert = &self.crate_path;
+        // abstract methods with a default implementation can access the shared state of the base
+        let as_ref_bound: TypeParamBound = syn::parse2(quote!{core::convert::AsRef<#base_type>}).unwrap();
+        target_trait.supertraits.push(as_ref_bound);
+        // a child which extends the base without implementing the trait fails on this bound, so the trait carries the message
+        let base_name = base_type.to_token_stream().to_string();
+        let message = format!("`{{Self}}` extends abstract `{}` but does not implement its abstract methods", base_name);
+        let label = format!("missing abstract methods of `{}`", base_name);
+        target_trait.attrs.push(syn::parse_quote!{#[diagnostic::on_unimplemented(message = #message, label = #label)]});
+        
+        let target_trait_ident = &target_trait.ident;
+        let (impl_generics, ty_generics, where_clause) = target_trait.generics.split_for_impl();
+        let trait_params = target_trait.generics.params.iter();
+        Ok(quote!{
+            #target_trait
+            
+            impl #impl_generics #roopert::__private::IsAbstract for #base_type #where_clause {}
+            
+            impl <#(#trait_params,)* __RoopertChild: #target_trait_ident #ty_generics + ?Sized> #roopert::__private::AbstractBase<__RoopertChild> for #base_type #where_clause {}
+        })
+    }
+    
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
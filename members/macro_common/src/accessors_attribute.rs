@@ -1,21 +1,34 @@
-use proc_macro2::{TokenStream};
+use proc_macro2::{TokenStream, Span};
 
-use syn::{ItemStruct, Field, Ident, Result, Token, punctuated::Punctuated, Type, Visibility, Expr, Path, Lit};
+use syn::{ItemStruct, Field, Ident, Result, Token, punctuated::Punctuated, Type, Visibility, Expr, Path, Lit, Error};
 use syn::parse::{Parse, ParseStream};
 
-use quote::{quote, ToTokens};
+use quote::{quote, format_ident, ToTokens};
 
-use super::{Generate, RoopertAttribute, RoopertAttributeType, GetterAttribute, SetterAttribute};
+use super::{Generate, CratePath, ExtendsAttribute, RoopertAttribute, RoopertAttributeType, GetterAttribute, SetterAttribute, CollectionAttribute};
 
-use super::parse::{is_getter_attribute, is_setter_attribute, is_roopert_attribute, single_path_segment};
+use super::parse::{is_getter_attribute, is_setter_attribute, is_collection_attribute, is_roopert_attribute, single_path_segment};
 
+/// Fields which get accessors without a field attribute
 #[cfg_attr(feature="verbose", derive(Debug))]
-enum AccessorAutoRule {
+pub enum AccessorAutoRule {
+    /// Every field
     All,
+    /// Fields without a visibility
     Private,
+    /// Fields which are not `pub`
+    PubCrate,
+    /// Every field except these ones
+    Except(Vec<Ident>),
+    /// No field
     No,
 }
 
+/// Field, getter function and setter function
+pub type DeclaredAccessors = (Ident, Option<Ident>, Option<Ident>);
+
+type FieldAccessors = (Vec<(FieldMetadata, GetterAttribute)>, Vec<(FieldMetadata, SetterAttribute)>, Vec<(FieldMetadata, CollectionAttribute)>);
+
 #[derive(Clone)]
 struct FieldMetadata {
     ty: Type,
@@ -36,92 +49,195 @@ impl AccessorAutoRule {
         match self {
             AccessorAutoRule::All => true,
             AccessorAutoRule::Private => field.vis == Visibility::Inherited,
+            AccessorAutoRule::PubCrate => !matches!(field.vis, Visibility::Public(_)),
+            AccessorAutoRule::Except(excluded) => !excluded.iter().any(|ident| field.ident.as_ref() == Some(ident)),
             AccessorAutoRule::No => false,
         }
     }
     
-    fn from_assignment_str(value: &str, input: ParseStream, ctx: &str) -> Result<AccessorAutoRule> {
+    fn unknown_field<'a>(&'a self, target_struct: &ItemStruct) -> Option<&'a Ident> {
+        match self {
+            AccessorAutoRule::Except(excluded) => excluded.iter()
+                .find(|ident| !target_struct.fields.iter().any(|field| field.ident.as_ref() == Some(*ident))),
+            _ => None,
+        }
+    }
+    
+    fn from_assignment_str(value: &str, expr: &Expr, ctx: &str) -> Result<AccessorAutoRule> {
         match value {
             "all" => Ok(AccessorAutoRule::All),
             "private" => Ok(AccessorAutoRule::Private),
+            "pubcrate" | "pub_crate" => Ok(AccessorAutoRule::PubCrate),
             "no" => Ok(AccessorAutoRule::No),
-            _ => Err(input.error(format!("Unrecognised right hand side of assignment in #[roopert(accesssor, ..., {} = {}]", ctx, value)))
+            _ => Err(Error::new_spanned(expr, format!("Unrecognised right hand side of assignment in #[roopert(accessors, ..., {} = {})]", ctx, expr.to_token_stream())))
         }
     }
 }
 
+/// Getters, setters and collection methods of the fields of a struct, from a `#[roopert(accessors, ...)]` attribute
 #[cfg_attr(feature="verbose", derive(Debug))]
 pub struct AccessorsAttribute {
     getter_rule: AccessorAutoRule,
     setter_rule: AccessorAutoRule,
+    collection_rule: AccessorAutoRule,
+    serde: bool,
+    crate_path: CratePath,
 }
 
 impl AccessorsAttribute {
-    fn rule_from_expr(assignee: &Ident, expr: &Expr, input: ParseStream) -> Result<AccessorAutoRule> {
+    /// Accessors only for the fields with `get`, `set` or `collection` field attributes
+    pub fn new() -> Self {
+        Self {
+            getter_rule: AccessorAutoRule::No,
+            setter_rule: AccessorAutoRule::No,
+            collection_rule: AccessorAutoRule::No,
+            serde: false,
+            crate_path: CratePath::default(),
+        }
+    }
+    
+    /// Sets the fields which get a getter without a field attribute (`get = ...`)
+    pub fn with_get(mut self, rule: AccessorAutoRule) -> Self {
+        self.getter_rule = rule;
+        self
+    }
+    
+    /// Sets the fields which get a setter without a field attribute (`set = ...`)
+    pub fn with_set(mut self, rule: AccessorAutoRule) -> Self {
+        self.setter_rule = rule;
+        self
+    }
+    
+    /// Sets the fields which get collection methods without a field attribute (`collection = ...`)
+    pub fn with_collection(mut self, rule: AccessorAutoRule) -> Self {
+        self.collection_rule = rule;
+        self
+    }
+    
+    /// Deserializes the fields with setters through their setters (`serde`)
+    #[cfg(feature = "serde")]
+    pub fn with_serde(mut self, serde: bool) -> Self {
+        self.serde = serde;
+        self
+    }
+    
+    fn rule_from_expr(assignee: &Ident, expr: &Expr) -> Result<AccessorAutoRule> {
         match expr {
             Expr::Path(var) => 
-                AccessorAutoRule::from_assignment_str(&single_path_segment(&var.path, input, accessor_path_err_rule)?.to_string().to_lowercase(), input, &assignee.to_string()),
+                AccessorAutoRule::from_assignment_str(&single_path_segment(&var.path, accessor_path_err_rule)?.to_string().to_lowercase(), expr, &assignee.to_string()),
             Expr::Lit(literal) => {
                 match &literal.lit {
                     Lit::Str(lit_str) => 
-                        AccessorAutoRule::from_assignment_str(&lit_str.value().to_lowercase(), input, &assignee.to_string()),
+                        AccessorAutoRule::from_assignment_str(&lit_str.value().to_lowercase(), expr, &assignee.to_string()),
                     //Lit::Int(lit_int) => {},
-                    _ => Err(input.error(format!("Unsupported literal type in right hand side of assignment in #[roopert(accessor, ..., {} = ???]", assignee.to_string())))
+                    _ => Err(Error::new_spanned(literal, format!("Unsupported literal type in right hand side of assignment in #[roopert(accessors, ..., {} = ???)]", assignee)))
                 }
             },
-            _ => Err(input.error(format!("Unrecognised right hand side of assignment in #[roopert(accessor, ..., {} = ???)]", assignee.to_string())))
+            Expr::Call(call) => {
+                let rule = match &*call.func {
+                    Expr::Path(var) => single_path_segment(&var.path, accessor_path_err_rule)?,
+                    _ => return Err(Error::new_spanned(&call.func, format!("Unrecognised right hand side of assignment in #[roopert(accessors, ..., {} = {})]", assignee, call.func.to_token_stream())))
+                };
+                match &rule.to_string().to_lowercase() as &str {
+                    "except" => {
+                        let mut excluded = Vec::with_capacity(call.args.len());
+                        for arg in call.args.iter() {
+                            match arg {
+                                Expr::Path(var) => excluded.push(single_path_segment(&var.path, accessor_path_err_rule)?),
+                                _ => return Err(Error::new_spanned(arg, format!("Expected field name in #[roopert(accessors, ..., {} = Except({}))]", assignee, arg.to_token_stream())))
+                            }
+                        }
+                        Ok(AccessorAutoRule::Except(excluded))
+                    },
+                    _ => Err(Error::new_spanned(&rule, format!("Unrecognised right hand side of assignment in #[roopert(accessors, ..., {} = {}(...))]", assignee, rule)))
+                }
+            },
+            _ => Err(Error::new_spanned(expr, format!("Unrecognised right hand side of assignment in #[roopert(accessors, ..., {} = ???)]", assignee)))
         }
     }
 }
 
+impl Default for AccessorsAttribute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Parse for AccessorsAttribute {
     fn parse(input: ParseStream) -> Result<Self> {
         // parse for optional get and set rules
         let mut get_rule = None;
         let mut set_rule = None;
+        let mut collection_rule = None;
+        let mut serde = false;
         let params = Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
         for p in params.iter() {
             match p {
                 Expr::Assign(assign) => {
                     if let Expr::Path(var) = &*assign.left {
-                        let ident = single_path_segment(&var.path, input, accessor_path_err_left)?;
+                        let ident = single_path_segment(&var.path, accessor_path_err_left)?;
                         match &ident.to_string().to_lowercase() as &str {
                             "get" => {
-                                get_rule = Some(Self::rule_from_expr(&ident, &*assign.right, input)?);
+                                get_rule = Some(Self::rule_from_expr(&ident, &assign.right)?);
                                 Ok(())
                             },
                             "set" => {
-                                set_rule = Some(Self::rule_from_expr(&ident, &*assign.right, input)?);
+                                set_rule = Some(Self::rule_from_expr(&ident, &assign.right)?);
+                                Ok(())
+                            },
+                            "collection" | "collections" => {
+                                collection_rule = Some(Self::rule_from_expr(&ident, &assign.right)?);
                                 Ok(())
                             }
-                            _ => Err(input.error(format!("Unrecognised assignment {} in #[roopert(accessor, ...)]", ident.to_string())))
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised assignment {} in #[roopert(accessors, ...)]", ident)))
                         }
                     } else {
-                        Err(input.error("Unsupported left hand side of assignment in #[roopert(accessor, ..., ??? = ...]"))
+                        Err(Error::new_spanned(&assign.left, "Unsupported left hand side of assignment in #[roopert(accessors, ..., ??? = ...)]"))
+                    }
+                },
+                Expr::Path(var) => {
+                    let ident = single_path_segment(&var.path, accessor_path_err_left)?;
+                    match &ident.to_string().to_lowercase() as &str {
+                        "serde" if cfg!(feature = "serde") => {
+                            serde = true;
+                            Ok(())
+                        },
+                        "serde" => Err(Error::new_spanned(&ident, "serde in #[roopert(accessors, ...)] requires the serde feature of roopert")),
+                        _ => Err(Error::new_spanned(&ident, format!("Unrecognised attribute parameter {} in #[roopert(accessors, ...)]", ident)))
                     }
                 },
-                _ => Err(input.error(format!("Unrecognised attribute parameter {} in #[roopert(accessor, ...)]", p.to_token_stream())))
+                _ => Err(Error::new_spanned(p, format!("Unrecognised attribute parameter {} in #[roopert(accessors, ...)]", p.to_token_stream())))
             }?;
         }
         Ok(Self{
             getter_rule: get_rule.unwrap_or(AccessorAutoRule::No),
             setter_rule: set_rule.unwrap_or(AccessorAutoRule::No),
+            collection_rule: collection_rule.unwrap_or(AccessorAutoRule::No),
+            serde,
+            crate_path: CratePath::default(),
         })
     }
 }
 
-impl Generate for AccessorsAttribute {
-    fn generate(&mut self, input: TokenStream) -> core::result::Result<TokenStream, String> {
-        //self.attr.generate(input)
-        let mut target_struct: ItemStruct = syn::parse(input.into()).map_err(|_| "Only named structs objects can have roopert accessors".to_string())?;
-        let target_struct_ident = &target_struct.ident.clone();
+impl AccessorsAttribute {
+    // accessors of every field, removing accessor attributes from the fields
+    fn collect_accessors(&self, target_struct: &mut ItemStruct) -> Result<FieldAccessors> {
         let mut getters: Vec<(FieldMetadata, GetterAttribute)> = Vec::new();
         let mut setters: Vec<(FieldMetadata, SetterAttribute)> = Vec::new();
+        let mut collections: Vec<(FieldMetadata, CollectionAttribute)> = Vec::new();
+        
+        for rule in [&self.getter_rule, &self.setter_rule, &self.collection_rule] {
+            if let Some(ident) = rule.unknown_field(target_struct) {
+                return Err(Error::new_spanned(ident, format!("Cannot exclude {} which is not a field of this struct in #[roopert(accessors, ... = Except(...))]", ident)));
+            }
+        }
         
         // find getter and setter attributes
         for field in target_struct.fields.iter_mut() {
             let mut setter_found = false;
             let mut getter_found = false;
+            let mut collection_found = false;
+            let mut skip_found = false;
             let field_meta = FieldMetadata::from_named_field(field);
             
             // get and set attributes must be removed after processing
@@ -132,29 +248,55 @@ impl Generate for AccessorsAttribute {
             for attr in &field.attrs {
                 let is_getter_path = is_getter_attribute(attr);
                 let is_setter_path = is_setter_attribute(attr);
+                let is_collection_path = is_collection_attribute(attr);
                 let is_roopert_path = is_roopert_attribute(attr);
                 if is_getter_path {
-                    let getter = attr.parse_args::<GetterAttribute>().map_err(|_| "Malformed roopert #[get] attribute".to_string())?;
+                    let getter = attr.parse_args::<GetterAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed roopert #[get] attribute: {}", e)))?;
                     getter_found = true;
-                    getters.push((field_meta.clone(), getter));
+                    if !getter.is_skip() {
+                        getters.push((field_meta.clone(), getter));
+                    }
                 } else if is_setter_path {
-                    let setter = attr.parse_args::<SetterAttribute>().map_err(|_| "Malformed roopert #[set] attribute".to_string())?;
+                    let setter = attr.parse_args::<SetterAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed roopert #[set] attribute: {}", e)))?;
                     setter_found = true;
-                    setters.push((field_meta.clone(), setter));
+                    if !setter.is_skip() {
+                        setters.push((field_meta.clone(), setter));
+                    }
+                } else if is_collection_path {
+                    let collection = attr.parse_args::<CollectionAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed roopert #[collection] attribute: {}", e)))?;
+                    collection_found = true;
+                    if !collection.is_skip() {
+                        collections.push((field_meta.clone(), collection));
+                    }
                 } else if is_roopert_path {
-                    let parsed_attr = attr.parse_args::<RoopertAttribute>().map_err(|e| format!("Malformed #[roopert(...)] attribute: {}", e))?;
+                    let parsed_attr = attr.parse_args::<RoopertAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed #[roopert(...)] attribute: {}", e)))?;
                     match parsed_attr.attr {
                         RoopertAttributeType::Get(getter) => {
                             getter_found = true;
-                            getters.push((
-                                field_meta.clone(), getter
-                            ))
+                            if !getter.is_skip() {
+                                getters.push((
+                                    field_meta.clone(), getter
+                                ))
+                            }
                         },
                         RoopertAttributeType::Set(setter) => {
                             setter_found = true;
-                            setters.push((
-                                field_meta.clone(), setter
-                            ))
+                            if !setter.is_skip() {
+                                setters.push((
+                                    field_meta.clone(), setter
+                                ))
+                            }
+                        },
+                        RoopertAttributeType::Collection(collection) => {
+                            collection_found = true;
+                            if !collection.is_skip() {
+                                collections.push((
+                                    field_meta.clone(), collection
+                                ))
+                            }
+                        },
+                        RoopertAttributeType::Skip(_) => {
+                            skip_found = true;
                         },
                         _ => {
                             new_attributes.push(attr.clone()); // keep non-related roopert attribute
@@ -166,15 +308,143 @@ impl Generate for AccessorsAttribute {
                 }
             }
             field.attrs = new_attributes;
-            if !setter_found && self.setter_rule.needs_accessor(field) {
+            if !setter_found && !skip_found && self.setter_rule.needs_accessor(field) {
                 setters.push((field_meta.clone(), SetterAttribute::with_accessor_defaults()));
             }
             
-            if !getter_found && self.getter_rule.needs_accessor(field) {
+            if !getter_found && !skip_found && self.getter_rule.needs_accessor(field) {
                 getters.push((field_meta.clone(), GetterAttribute::with_accessor_defaults()));
             }
+            
+            if !collection_found && !skip_found && self.collection_rule.needs_accessor(field) && CollectionAttribute::is_collection_type(&field.ty) {
+                collections.push((field_meta.clone(), CollectionAttribute::with_accessor_defaults()));
+            }
         }
         
+        Ok((getters, setters, collections))
+    }
+    
+    /// Getter and setter functions of each field, declared by a `#[roopert(accessors, ...)]` attribute of the struct which has not been expanded yet
+    pub fn declared_accessors(target_struct: &ItemStruct) -> Result<Vec<DeclaredAccessors>> {
+        let mut accessors = Vec::new();
+        for (_, parsed_attr) in RoopertAttribute::declared(&target_struct.attrs)? {
+            if let RoopertAttributeType::Accessors(accessors_attr) = parsed_attr.attr {
+                let (getters, setters, _) = accessors_attr.collect_accessors(&mut target_struct.clone())?;
+                for field in target_struct.fields.iter().filter_map(|field| field.ident.as_ref()) {
+                    let getter = getters.iter().find(|(meta, _)| &meta.ident == field).map(|(_, getter)| getter.getter_ident(field));
+                    let setter = setters.iter().find(|(meta, _)| &meta.ident == field).map(|(_, setter)| setter.setter_ident(field));
+                    accessors.push((field.clone(), getter, setter));
+                }
+            }
+        }
+        Ok(accessors)
+    }
+}
+
+impl AccessorsAttribute {
+    // deserializes into a copy of the struct, then moves the fields with setters into the struct through their setters
+    // a panic of a setter hook becomes a deserialization error (with the std feature of roopert, which can catch it)
+    fn impl_deserialize_from(&self, target_struct: &mut ItemStruct, setter_fields: &[(Ident, Ident)]) -> Result<TokenStream> {
+        let target_struct_ident = &target_struct.ident;
+        let roopert = &self.crate_path;
+        let serde_path = quote!{#roopert::__private::serde}.to_string();
+        let shadow_ident = format_ident!("__RoopertDeserialize{}", target_struct_ident);
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        let vis = &target_struct.vis;
+        let generics = &target_struct.generics;
+        // container attributes which change how the struct itself is converted are not copied
+        let shadow_attrs = target_struct.attrs.iter()
+            .filter(|attr| attr.path.is_ident("serde"))
+            .filter(|attr| !attr.tokens.clone().into_iter().any(|token| matches!(&token, proc_macro2::TokenTree::Group(group)
+                if group.stream().into_iter().any(|inner| matches!(&inner, proc_macro2::TokenTree::Ident(ident)
+                    if ident == "from" || ident == "try_from" || ident == "into" || ident == "crate")))));
+        // parents flattened by an extends attribute below this one do not have their serde attribute yet
+        let flattened_fields = ExtendsAttribute::declared_flattened_fields(target_struct)?;
+        let shadow_fields = target_struct.fields.iter().map(|field| {
+            let field_ident = &field.ident;
+            let field_type = &field.ty;
+            let serde_attrs = field.attrs.iter().filter(|attr| attr.path.is_ident("serde"));
+            let flatten = field.ident.as_ref()
+                .filter(|field_ident| flattened_fields.contains(field_ident))
+                .map(|_| quote!{#[serde(flatten)]});
+            quote!{
+                #(#serde_attrs)*
+                #flatten
+                #field_ident: #field_type
+            }
+        });
+        let field_idents: Vec<&Ident> = target_struct.fields.iter().filter_map(|field| field.ident.as_ref()).collect();
+        let initial_values = field_idents.iter().map(|field_ident| {
+            if setter_fields.iter().any(|(setter_field, _)| setter_field == *field_ident) {
+                quote!{#field_ident: core::default::Default::default()}
+            } else {
+                quote!{#field_ident}
+            }
+        });
+        let setter_calls = setter_fields.iter().map(|(field_ident, setter_ident)| quote!{
+            target.#setter_ident(#field_ident);
+        });
+        let shadow_type = quote!{#shadow_ident #ty_generics}.to_string();
+        // fields with setters start from their default value before the setter is called
+        let mut from_generics = target_struct.generics.clone();
+        for field in target_struct.fields.iter().filter(|field| setter_fields.iter().any(|(setter_field, _)| field.ident.as_ref() == Some(setter_field))) {
+            let field_type = &field.ty;
+            from_generics.make_where_clause().predicates.push(syn::parse_quote!{#field_type: core::default::Default});
+        }
+        let (_, _, from_where_clause) = from_generics.split_for_impl();
+        let tokens = quote!{
+            #[doc(hidden)]
+            #[derive(#roopert::__private::serde::Deserialize)]
+            #[serde(crate = #serde_path)]
+            #(#shadow_attrs)*
+            #vis struct #shadow_ident #generics #where_clause {
+                #(#shadow_fields,)*
+            }
+            
+            impl #impl_generics core::convert::TryFrom<#shadow_ident #ty_generics> for #target_struct_ident #ty_generics #from_where_clause {
+                type Error = #roopert::__private::SetterHookError;
+                
+                fn try_from(shadow: #shadow_ident #ty_generics) -> core::result::Result<Self, Self::Error> {
+                    let #shadow_ident { #(#field_idents,)* } = shadow;
+                    #roopert::__private::run_setter_hooks(move || {
+                        let mut target = Self {
+                            #(#initial_values,)*
+                        };
+                        #(#setter_calls)*
+                        target
+                    })
+                }
+            }
+        };
+        target_struct.attrs.push(syn::parse_quote!{#[serde(try_from = #shadow_type)]});
+        Ok(tokens)
+    }
+}
+
+impl Generate for AccessorsAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        //self.attr.generate(input)
+        let mut target_struct: ItemStruct = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only named structs objects can have roopert accessors"))?;
+        let target_struct_ident = &target_struct.ident.clone();
+        // attributes which read the accessors from this attribute must be expanded before it
+        for (attr, parsed_attr) in RoopertAttribute::declared(&target_struct.attrs)? {
+            let dependent = match parsed_attr.attr {
+                RoopertAttributeType::Reflect(_) => "reflect",
+                RoopertAttributeType::Properties(_) => "properties",
+                _ => continue,
+            };
+            return Err(Error::new_spanned(attr, format!("#[roopert({})] must be above #[roopert(accessors, ...)], since it reads the accessors of the struct from it", dependent)));
+        }
+        let (getters, setters, collections) = self.collect_accessors(&mut target_struct)?;
+        let deserialize_tokens = if self.serde {
+            let setter_fields: Vec<(Ident, Ident)> = setters.iter()
+                .map(|(meta, attr)| (meta.ident.clone(), attr.setter_ident(&meta.ident)))
+                .collect();
+            Some(self.impl_deserialize_from(&mut target_struct, &setter_fields)?)
+        } else {
+            None
+        };
+        
         // generate accessors
         let mut getter_tokens = Vec::new();
         for (meta, attr) in getters {
@@ -182,7 +452,11 @@ impl Generate for AccessorsAttribute {
         }
         let mut setter_tokens = Vec::new();
         for (meta, attr) in setters {
-            setter_tokens.push(attr.impl_set_fn(&meta.ident, &meta.ty));
+            setter_tokens.push(attr.impl_set_fns(&meta.ident, &meta.ty));
+        }
+        let mut collection_tokens = Vec::new();
+        for (meta, attr) in collections {
+            collection_tokens.push(attr.impl_collection_fns(&meta.ident, &meta.ty)?);
         }
         let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
         Ok(quote!{
@@ -192,10 +466,18 @@ impl Generate for AccessorsAttribute {
                 #(#getter_tokens)*
                 
                 #(#setter_tokens)*
+                
+                #(#collection_tokens)*
             }
+            
+            #deserialize_tokens
         })
     }
     
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
     fn auto_append(&self) -> bool {false}
 }
 
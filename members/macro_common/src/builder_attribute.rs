@@ -0,0 +1,457 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemStruct, Ident, Result, Token, punctuated::Punctuated, Expr, Path, Lit, Type, GenericParam, Error};
+use syn::parse::{Parse, ParseStream};
+use syn::ext::IdentExt;
+
+use quote::{quote, format_ident, ToTokens};
+
+use super::{Generate, CratePath, ExtendsAttribute, RoopertAttribute, RoopertAttributeType};
+
+use super::parse::{is_builder_attribute, is_roopert_attribute, single_path_segment};
+
+/// Builder of a struct, from a `#[roopert(builder, ...)]` attribute, or builder options of a field, from a `#[builder(...)]` field attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct BuilderAttribute {
+    name: Option<String>,
+    typestate: bool,
+    into: bool,
+    default: bool,
+    default_value: Option<Expr>,
+    crate_path: CratePath,
+}
+
+struct BuilderField<'a> {
+    ident: &'a Ident,
+    ty: &'a Type,
+    attr: BuilderAttribute,
+}
+
+impl BuilderField<'_> {
+    fn is_required(&self) -> bool {
+        !self.attr.default && self.attr.default_value.is_none()
+    }
+    
+    fn camel_case_ident(&self) -> String {
+        self.ident.unraw().to_string()
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                match chars.next() {
+                    Some(first) => first.to_uppercase().chain(chars).collect::<String>(),
+                    None => String::new(),
+                }
+            })
+            .collect()
+    }
+}
+
+impl BuilderAttribute {
+    /// Builder named `{Struct}Builder` which checks required fields at runtime, or a required field
+    pub fn with_field_defaults() -> Self {
+        Self {
+            name: None,
+            typestate: false,
+            into: false,
+            default: false,
+            default_value: None,
+            crate_path: CratePath::default(),
+        }
+    }
+    
+    /// Sets the name of the builder struct
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    
+    /// Checks required fields at compile time with typestate generics (`typestate`)
+    pub fn with_typestate(mut self, typestate: bool) -> Self {
+        self.typestate = typestate;
+        self
+    }
+    
+    /// Makes the field method accept any type which converts into the field type (`into`)
+    pub fn with_into(mut self, into: bool) -> Self {
+        self.into = into;
+        self
+    }
+    
+    /// Makes the field optional, using `Default::default()` when it is not set (`default`)
+    pub fn with_default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+    
+    /// Makes the field optional, using the expression when it is not set (`default = ...`)
+    pub fn with_default_value(mut self, value: Expr) -> Self {
+        self.default_value = Some(value);
+        self
+    }
+    
+    fn builder_ident(&self, target_struct: &ItemStruct) -> Ident {
+        self.name.as_ref().map(|name| format_ident!("{}", name))
+            .unwrap_or_else(|| format_ident!("{}Builder", target_struct.ident))
+    }
+    
+    fn impl_field_fn(&self, field: &BuilderField) -> TokenStream {
+        let field_ident = field.ident;
+        let (param_type, value) = self.field_param(field);
+        quote!{
+            pub fn #field_ident(mut self, x: #param_type) -> Self {
+                self.#field_ident = core::option::Option::Some(#value);
+                self
+            }
+        }
+    }
+    
+    fn field_param(&self, field: &BuilderField) -> (TokenStream, TokenStream) {
+        let field_type = field.ty;
+        if self.into || field.attr.into {
+            (quote!{impl core::convert::Into<#field_type>}, quote!{core::convert::Into::into(x)})
+        } else {
+            (quote!{#field_type}, quote!{x})
+        }
+    }
+    
+    fn impl_field_build(&self, target_struct: &ItemStruct, field: &BuilderField) -> TokenStream {
+        let field_ident = field.ident;
+        if let Some(value) = &field.attr.default_value {
+            quote!{self.#field_ident.unwrap_or_else(|| #value)}
+        } else if field.attr.default {
+            quote!{self.#field_ident.unwrap_or_default()}
+        } else {
+            let target_name = target_struct.ident.to_string();
+            let field_name = field_ident.unraw().to_string();
+            let roopert = &self.crate_path;
+            quote!{
+                match self.#field_ident {
+                    core::option::Option::Some(x) => x,
+                    core::option::Option::None => return core::result::Result::Err(#roopert::BuildError::MissingField {
+                        target: #target_name,
+                        field: #field_name,
+                    }),
+                }
+            }
+        }
+    }
+    
+    fn impl_typestate_builder(&self, target_struct: &ItemStruct, fields: &[BuilderField], parents: &[(Type, Ident)]) -> TokenStream {
+        let target_struct_ident = &target_struct.ident;
+        let target_struct_vis = &target_struct.vis;
+        let builder_ident = self.builder_ident(target_struct);
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        let field_idents: Vec<&Ident> = fields.iter().map(|field| field.ident).collect();
+        let field_types: Vec<&Type> = fields.iter().map(|field| field.ty).collect();
+        
+        // every required field has a generic state parameter, which is either the field's set or missing marker type
+        let required: Vec<&BuilderField> = fields.iter().filter(|field| field.is_required()).collect();
+        let state_params: Vec<Ident> = required.iter().map(|field| format_ident!("__{}", field.camel_case_ident())).collect();
+        let set_markers: Vec<Ident> = required.iter().map(|field| format_ident!("{}{}Set", builder_ident, field.camel_case_ident())).collect();
+        let missing_markers: Vec<Ident> = required.iter().map(|field| format_ident!("{}{}Missing", builder_ident, field.camel_case_ident())).collect();
+        let struct_params = target_struct.generics.params.iter();
+        let struct_args = generic_args(target_struct);
+        let all_missing = quote!{<#(#struct_args,)* #(#missing_markers),*>};
+        let all_set = quote!{<#(#struct_args,)* #(#set_markers),*>};
+        let any_state = quote!{<#(#struct_args,)* #(#state_params),*>};
+        let any_state_params = quote!{<#(#struct_params,)* #(#state_params),*>};
+        
+        // fluent field functions either change the state of a required field or keep the state of an optional field
+        let field_fns: Vec<TokenStream> = fields.iter().map(|field| {
+            let field_ident = field.ident;
+            let (param_type, value) = self.field_param(field);
+            match required.iter().position(|required_field| required_field.ident == field.ident) {
+                Some(index) => {
+                    let next_state = state_params.iter().enumerate()
+                        .map(|(i, param)| if i == index { set_markers[i].to_token_stream() } else { param.to_token_stream() });
+                    let other_fields = field_idents.iter().filter(|other| **other != field_ident);
+                    quote!{
+                        pub fn #field_ident(self, x: #param_type) -> #builder_ident<#(#struct_args,)* #(#next_state),*> {
+                            #builder_ident {
+                                #field_ident: core::option::Option::Some(#value),
+                                #(#other_fields: self.#other_fields,)*
+                                __state: core::marker::PhantomData,
+                            }
+                        }
+                    }
+                },
+                None => self.impl_field_fn(field),
+            }
+        }).collect();
+        let field_builds: Vec<TokenStream> = fields.iter().map(|field| {
+            let field_ident = field.ident;
+            if field.is_required() {
+                quote!{
+                    match self.#field_ident {
+                        core::option::Option::Some(x) => x,
+                        core::option::Option::None => unreachable!("required field state is set"),
+                    }
+                }
+            } else {
+                self.impl_field_build(target_struct, field)
+            }
+        }).collect();
+        let parent_impls: Vec<TokenStream> = parents.iter().map(|(parent_type, parent_field)| {
+            let parent_state = state_params.iter().zip(required.iter()).enumerate()
+                .map(|(i, (_, field))| if field.ident == parent_field { set_markers[i].to_token_stream() } else { missing_markers[i].to_token_stream() });
+            quote!{
+                impl #impl_generics core::convert::From<#parent_type> for #builder_ident<#(#struct_args,)* #(#parent_state),*> #where_clause {
+                    fn from(parent: #parent_type) -> Self {
+                        #builder_ident::new().#parent_field(parent)
+                    }
+                }
+            }
+        }).collect();
+        quote!{
+            #(
+                #[allow(dead_code)]
+                #target_struct_vis struct #set_markers;
+                
+                #[allow(dead_code)]
+                #target_struct_vis struct #missing_markers;
+            )*
+            
+            #target_struct_vis struct #builder_ident #any_state_params #where_clause {
+                #(#field_idents: core::option::Option<#field_types>,)*
+                __state: core::marker::PhantomData<fn() -> (#(#state_params,)*)>,
+            }
+            
+            impl #impl_generics #builder_ident #all_missing #where_clause {
+                pub fn new() -> Self {
+                    Self {
+                        #(#field_idents: core::option::Option::None,)*
+                        __state: core::marker::PhantomData,
+                    }
+                }
+            }
+            
+            impl #impl_generics core::default::Default for #builder_ident #all_missing #where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+            
+            impl #any_state_params #builder_ident #any_state #where_clause {
+                #(#field_fns)*
+            }
+            
+            impl #impl_generics #builder_ident #all_set #where_clause {
+                pub fn build(self) -> #target_struct_ident #ty_generics {
+                    #target_struct_ident {
+                        #(#field_idents: #field_builds),*
+                    }
+                }
+            }
+            
+            #(#parent_impls)*
+            
+            impl #impl_generics #target_struct_ident #ty_generics #where_clause {
+                pub fn builder() -> #builder_ident #all_missing {
+                    #builder_ident::new()
+                }
+            }
+        }
+    }
+    
+    fn impl_builder(&self, target_struct: &ItemStruct, fields: &[BuilderField], parents: &[(Type, Ident)]) -> TokenStream {
+        let target_struct_ident = &target_struct.ident;
+        let target_struct_vis = &target_struct.vis;
+        let builder_ident = self.builder_ident(target_struct);
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        let generics = &target_struct.generics;
+        let field_idents: Vec<&Ident> = fields.iter().map(|field| field.ident).collect();
+        let field_types: Vec<&Type> = fields.iter().map(|field| field.ty).collect();
+        let field_fns: Vec<TokenStream> = fields.iter().map(|field| self.impl_field_fn(field)).collect();
+        let field_builds: Vec<TokenStream> = fields.iter().map(|field| self.impl_field_build(target_struct, field)).collect();
+        let roopert = &self.crate_path;
+        let parent_impls: Vec<TokenStream> = parents.iter().map(|(parent_type, parent_field)| quote!{
+            impl #impl_generics core::convert::From<#parent_type> for #builder_ident #ty_generics #where_clause {
+                fn from(parent: #parent_type) -> Self {
+                    Self::new().#parent_field(parent)
+                }
+            }
+        }).collect();
+        quote!{
+            #target_struct_vis struct #builder_ident #generics #where_clause {
+                #(#field_idents: core::option::Option<#field_types>),*
+            }
+            
+            impl #impl_generics #builder_ident #ty_generics #where_clause {
+                pub fn new() -> Self {
+                    Self {
+                        #(#field_idents: core::option::Option::None),*
+                    }
+                }
+                
+                #(#field_fns)*
+                
+                pub fn build(self) -> core::result::Result<#target_struct_ident #ty_generics, #roopert::BuildError> {
+                    core::result::Result::Ok(#target_struct_ident {
+                        #(#field_idents: #field_builds),*
+                    })
+                }
+            }
+            
+            impl #impl_generics core::default::Default for #builder_ident #ty_generics #where_clause {
+                fn default() -> Self {
+                    Self::new()
+                }
+            }
+            
+            #(#parent_impls)*
+            
+            impl #impl_generics #target_struct_ident #ty_generics #where_clause {
+                pub fn builder() -> #builder_ident #ty_generics {
+                    #builder_ident::new()
+                }
+            }
+        }
+    }
+    
+    #[inline]
+    fn name_to_string(rhs: &Expr) -> Result<String> {
+        match rhs {
+            Expr::Lit(lit) => {
+                match &lit.lit {
+                    Lit::Str(lit_str) => Ok(lit_str.value()),
+                    _ => Err(Error::new_spanned(lit, format!("Invalid literal in right hand side of name parameter #[roopert(builder, name = {})]", lit.to_token_stream())))
+                }
+            },
+            _ => Err(Error::new_spanned(rhs, format!("Unrecognised right hand side of name parameter #[roopert(builder, name = {})]", rhs.to_token_stream())))
+        }
+    }
+}
+
+impl Parse for BuilderAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attr = Self::with_field_defaults();
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(builder, ...)]: {}", e)))?;
+        for param in params.iter() {
+            match param {
+                Expr::Assign(assign) => {
+                    if let Expr::Path(var) = &*assign.left {
+                        let ident = single_path_segment(&var.path, builder_lhs_err)?;
+                        match &ident.to_string().to_lowercase() as &str {
+                            "name" => {
+                                attr.name = Some(Self::name_to_string(&assign.right)?);
+                                Ok(())
+                            },
+                            "default" => {
+                                attr.default_value = Some((*assign.right).clone());
+                                Ok(())
+                            },
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(builder, ...)]", ident)))
+                        }
+                    } else {
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(builder, ...)]", assign.to_token_stream())))
+                    }
+                },
+                Expr::Path(var) => {
+                    let ident = single_path_segment(&var.path, builder_lhs_err)?;
+                    match &ident.to_string().to_lowercase() as &str {
+                        "into" => {
+                            attr.into = true;
+                            Ok(())
+                        },
+                        "typestate" => {
+                            attr.typestate = true;
+                            Ok(())
+                        },
+                        "default" => {
+                            attr.default = true;
+                            Ok(())
+                        },
+                        _ => Err(Error::new_spanned(&ident, format!("Unrecognised attribute parameter {} in #[roopert(builder, ...)]", ident)))
+                    }
+                },
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(builder, ...)]", param.to_token_stream())))
+            }?;
+        }
+        Ok(attr)
+    }
+}
+
+impl Generate for BuilderAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let mut target_struct: ItemStruct = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only named structs objects can have a roopert builder"))?;
+        // parent fields are only known if the extends attribute has not been expanded yet
+        let parents = ExtendsAttribute::declared_parents(&target_struct)?;
+        let mut field_attrs: Vec<BuilderAttribute> = Vec::with_capacity(target_struct.fields.len());
+        
+        // find field-level builder attributes
+        for field in target_struct.fields.iter_mut() {
+            if field.ident.is_none() {
+                return Err(Error::new_spanned(&*field, "Only named structs objects can have a roopert builder"));
+            }
+            let mut field_attr = None;
+            
+            // builder attributes must be removed after processing
+            // this stores any remaining attributes (which may be used by other macros or the compiler)
+            let mut new_attributes = Vec::with_capacity(field.attrs.len());
+            for attr in &field.attrs {
+                if is_builder_attribute(attr) {
+                    field_attr = Some(attr.parse_args::<BuilderAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed roopert #[builder] attribute: {}", e)))?);
+                } else if is_roopert_attribute(attr) {
+                    let parsed_attr = attr.parse_args::<RoopertAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed #[roopert(...)] attribute: {}", e)))?;
+                    match parsed_attr.attr {
+                        RoopertAttributeType::Builder(builder_attr) => field_attr = Some(builder_attr),
+                        _ => new_attributes.push(attr.clone()), // keep non-related roopert attribute
+                    }
+                } else {
+                    new_attributes.push(attr.clone()); // keep unrelated attribute
+                }
+            }
+            field.attrs = new_attributes;
+            field_attrs.push(field_attr.unwrap_or_else(Self::with_field_defaults));
+        }
+        
+        let fields: Vec<BuilderField> = target_struct.fields.iter()
+            .zip(field_attrs)
+            .map(|(field, attr)| BuilderField {
+                ident: field.ident.as_ref().unwrap(),
+                ty: &field.ty,
+                attr,
+            })
+            .collect();
+        let builder = if self.typestate {
+            // required fields are told apart by marker types named after the field in camel case
+            let mut marker_names: Vec<(String, &Ident)> = Vec::new();
+            for field in fields.iter().filter(|field| field.is_required()) {
+                let marker_name = field.camel_case_ident();
+                if let Some((_, other_ident)) = marker_names.iter().find(|(name, _)| name == &marker_name) {
+                    return Err(Error::new_spanned(field.ident, format!("Field {} has the same typestate marker name {} as field {} in #[roopert(builder, typestate)] (rename one of the fields)", field.ident.unraw(), marker_name, other_ident.unraw())));
+                }
+                marker_names.push((marker_name, field.ident));
+            }
+            self.impl_typestate_builder(&target_struct, &fields, &parents)
+        } else {
+            self.impl_builder(&target_struct, &fields, &parents)
+        };
+        Ok(quote!{
+            #target_struct
+            
+            #builder
+        })
+    }
+    
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
+
+fn generic_args(target_struct: &ItemStruct) -> Vec<TokenStream> {
+    target_struct.generics.params.iter()
+        .map(|param| match param {
+            GenericParam::Type(type_param) => type_param.ident.to_token_stream(),
+            GenericParam::Lifetime(lifetime_def) => lifetime_def.lifetime.to_token_stream(),
+            GenericParam::Const(const_param) => const_param.ident.to_token_stream(),
+        })
+        .collect()
+}
+
+fn builder_lhs_err(path: &Path) -> String {
+    format!("Unrecognised left hand side of assignment {} in #[roopert(builder, ...)]", path.to_token_stream())
+}
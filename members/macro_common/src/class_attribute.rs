@@ -0,0 +1,189 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemImpl, ImplItem, Ident, Result, Token, punctuated::Punctuated, Expr, Path, Lit, Type, Visibility, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, format_ident, ToTokens};
+
+use super::Generate;
+use super::methods::{ForwardedMethod, ReceiverKind, snake_case};
+
+use super::parse::single_path_segment;
+
+/// Overridable methods of a type, from a `#[roopert(class, ...)]` attribute on its inherent impl block
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct ClassAttribute {
+    name: Option<String>,
+    vis: Option<Visibility>,
+}
+
+impl ClassAttribute {
+    /// Public class trait named `{Type}Class`
+    pub fn new() -> Self {
+        Self {
+            name: None,
+            vis: None,
+        }
+    }
+    
+    /// Sets the name of the class trait
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    
+    /// Sets the visibility of the class trait
+    pub fn with_vis(mut self, vis: Visibility) -> Self {
+        self.vis = Some(vis);
+        self
+    }
+    
+    /// Trait generated for a class type, i.e. `Animal<T>` -> `AnimalClass<T>`
+    pub fn class_trait_path(class_type: &Type) -> Result<Path> {
+        let mut path = Self::class_type_path(class_type)?.clone();
+        let last_segment = path.segments.last_mut().unwrap();
+        last_segment.ident = format_ident!("{}Class", last_segment.ident);
+        Ok(path)
+    }
+    
+    /// Class trait functions for accessing the class type, i.e. `Animal` -> `(as_animal, as_animal_mut)`
+    pub fn accessor_idents(class_type: &Type) -> Result<(Ident, Ident)> {
+        let path = Self::class_type_path(class_type)?;
+        let snake_ident = snake_case(&path.segments.last().unwrap().ident.to_string());
+        Ok((format_ident!("as_{}", snake_ident), format_ident!("as_{}_mut", snake_ident)))
+    }
+    
+    fn class_type_path(class_type: &Type) -> Result<&Path> {
+        match class_type {
+            Type::Path(type_path) if type_path.qself.is_none() && !type_path.path.segments.is_empty() => Ok(&type_path.path),
+            _ => Err(Error::new_spanned(class_type, format!("Unsupported class type {} (only named types can be classes)", class_type.to_token_stream()))),
+        }
+    }
+    
+    #[inline]
+    fn str_to_string(rhs: &Expr, ctx: &str) -> Result<String> {
+        match rhs {
+            Expr::Lit(lit) => {
+                match &lit.lit {
+                    Lit::Str(lit_str) => Ok(lit_str.value()),
+                    _ => Err(Error::new_spanned(lit, format!("Invalid literal in right hand side of {} parameter #[roopert(class, {} = {})]", ctx, ctx, lit.to_token_stream())))
+                }
+            },
+            _ => Err(Error::new_spanned(rhs, format!("Unrecognised right hand side of {} parameter #[roopert(class, {} = {})]", ctx, ctx, rhs.to_token_stream())))
+        }
+    }
+}
+
+impl Default for ClassAttribute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parse for ClassAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut name = None;
+        let mut vis = None;
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(class, ...)]: {}", e)))?;
+        for param in params.iter() {
+            match param {
+                Expr::Assign(assign) => {
+                    if let Expr::Path(var) = &*assign.left {
+                        let ident = single_path_segment(&var.path, class_lhs_err)?;
+                        match &ident.to_string().to_lowercase() as &str {
+                            "name" => {
+                                name = Some(Self::str_to_string(&assign.right, "name")?);
+                                Ok(())
+                            },
+                            "vis" | "visibility" => {
+                                let vis_str = Self::str_to_string(&assign.right, "vis")?;
+                                vis = Some(syn::parse_str::<Visibility>(&vis_str).map_err(|e| Error::new(e.span(), format!("Invalid visibility {} in #[roopert(class, vis = ...)]: {}", vis_str, e)))?);
+                                Ok(())
+                            },
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(class, ...)]", ident)))
+                        }
+                    } else {
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(class, ...)]", assign.to_token_stream())))
+                    }
+                },
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(class, ...)]", param.to_token_stream())))
+            }?;
+        }
+        Ok(Self {
+            name,
+            vis,
+        })
+    }
+}
+
+impl Generate for ClassAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let target_impl: ItemImpl = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only impl blocks can be a roopert class"))?;
+        if let Some((_, trait_path, _)) = &target_impl.trait_ {
+            return Err(Error::new_spanned(trait_path, "Only inherent impl blocks can be a roopert class, not trait impl blocks"));
+        }
+        let self_ty = &target_impl.self_ty;
+        let trait_ident = match &self.name {
+            Some(name) => format_ident!("{}", name),
+            None => Self::class_trait_path(self_ty)?.segments.last().unwrap().ident.clone(),
+        };
+        let (as_ref_fn, as_mut_fn) = Self::accessor_idents(self_ty)?;
+        let trait_vis = self.vis.as_ref().map(|vis| vis.to_token_stream())
+            .unwrap_or_else(|| quote!{pub});
+        let (impl_generics, ty_generics, where_clause) = target_impl.generics.split_for_impl();
+        let trait_generics = &target_impl.generics;
+        
+        // every method with a &self or &mut self receiver can be overridden
+        let mut methods = Vec::new();
+        for item in target_impl.items.iter() {
+            if let ImplItem::Method(method) = item {
+                let forwarded = ForwardedMethod::from_signature(&method.sig);
+                if !forwarded.has_reference_receiver() || forwarded.mentions_self() {
+                    continue;
+                }
+                let accessor = if forwarded.receiver == ReceiverKind::RefMut {
+                    quote!{<Self as #trait_ident #ty_generics>::#as_mut_fn(self)}
+                } else {
+                    quote!{<Self as #trait_ident #ty_generics>::#as_ref_fn(self)}
+                };
+                let method_ident = forwarded.ident();
+                let call = forwarded.call(quote!{<#self_ty>::#method_ident}, Some(accessor));
+                let docs = method.attrs.iter().filter(|attr| attr.path.is_ident("doc"));
+                let sig = &forwarded.sig;
+                methods.push(quote!{
+                    #(#docs)*
+                    #sig {
+                        #call
+                    }
+                });
+            }
+        }
+        Ok(quote!{
+            #target_impl
+            
+            #trait_vis trait #trait_ident #trait_generics #where_clause {
+                fn #as_ref_fn(&self) -> &#self_ty;
+                
+                fn #as_mut_fn(&mut self) -> &mut #self_ty;
+                
+                #(#methods)*
+            }
+            
+            impl #impl_generics #trait_ident #ty_generics for #self_ty #where_clause {
+                fn #as_ref_fn(&self) -> &#self_ty {
+                    self
+                }
+                
+                fn #as_mut_fn(&mut self) -> &mut #self_ty {
+                    self
+                }
+            }
+        })
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
+
+fn class_lhs_err(path: &Path) -> String {
+    format!("Unrecognised left hand side of assignment {} in #[roopert(class, ...)]", path.to_token_stream())
+}
@@ -0,0 +1,305 @@
+use syn::{Result, Ident, Type, Expr, Token, punctuated::Punctuated, Path, Lit, GenericArgument, PathArguments, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, format_ident, ToTokens};
+
+use proc_macro2::{TokenStream};
+
+use super::Generate;
+use super::parse::single_path_segment;
+
+enum CollectionKind<'a> {
+    List(&'a Type),
+    Map(&'a Type, &'a Type),
+    Set(&'a Type),
+}
+
+impl<'a> CollectionKind<'a> {
+    fn from_type(ty: &'a Type) -> Option<Self> {
+        let last_segment = match ty {
+            Type::Path(type_path) => type_path.path.segments.last()?,
+            _ => return None,
+        };
+        let mut type_args = Vec::new();
+        if let PathArguments::AngleBracketed(args) = &last_segment.arguments {
+            for arg in args.args.iter() {
+                if let GenericArgument::Type(arg_ty) = arg {
+                    type_args.push(arg_ty);
+                }
+            }
+        }
+        let mut type_args = type_args.into_iter();
+        match &last_segment.ident.to_string() as &str {
+            "Vec" => Some(Self::List(type_args.next()?)),
+            "HashMap" | "BTreeMap" => Some(Self::Map(type_args.next()?, type_args.next()?)),
+            "HashSet" | "BTreeSet" => Some(Self::Set(type_args.next()?)),
+            _ => None,
+        }
+    }
+}
+
+/// Collection methods of a field, from a `#[collection(...)]` or `#[roopert(collection, ...)]` field attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct CollectionAttribute {
+    pre: Option<Expr>,
+    post: Option<Expr>,
+    singular: Option<String>,
+    skip: bool,
+}
+
+impl CollectionAttribute {
+    /// Collection methods named after the singular of the field name
+    pub fn with_accessor_defaults() -> Self {
+        Self {
+            pre: None,
+            post: None,
+            singular: None,
+            skip: false,
+        }
+    }
+    
+    /// Sets the singular used in the names of the collection methods
+    pub fn with_singular(mut self, singular: impl Into<String>) -> Self {
+        self.singular = Some(singular.into());
+        self
+    }
+    
+    /// Evaluates the expression at the start of the methods which modify the collection
+    pub fn with_pre(mut self, pre: Expr) -> Self {
+        self.pre = Some(pre);
+        self
+    }
+    
+    /// Evaluates the expression at the end of the methods which modify the collection
+    pub fn with_post(mut self, post: Expr) -> Self {
+        self.post = Some(post);
+        self
+    }
+    
+    /// Whether the field does not have collection methods
+    pub fn is_skip(&self) -> bool {
+        self.skip
+    }
+    
+    /// Whether the type is a supported collection (`Vec`, `HashMap`, `BTreeMap`, `HashSet` or `BTreeSet`)
+    pub fn is_collection_type(ty: &Type) -> bool {
+        CollectionKind::from_type(ty).is_some()
+    }
+    
+    /// Collection methods of the field, to put in an inherent impl block of the struct
+    pub fn impl_collection_fns(&self, target_field: &Ident, parent_type: &Type) -> Result<TokenStream> {
+        let kind = CollectionKind::from_type(parent_type)
+            .ok_or_else(|| Error::new_spanned(parent_type, format!("Unsupported collection type {} for field {} (use Vec, HashMap, BTreeMap, HashSet or BTreeSet)", parent_type.to_token_stream(), target_field)))?;
+        let plural = target_field.to_string();
+        let singular = self.singular.clone().unwrap_or_else(|| Self::singular_of(&plural));
+        let iter_fn_name = format_ident!("{}_iter", plural);
+        let clear_fn_name = format_ident!("clear_{}", plural);
+        let remove_fn_name = format_ident!("remove_{}", singular);
+        let pre_op = match self.pre.as_ref() {
+            Some(op) => quote!{#op;}.to_token_stream(),
+            None => quote!{}.to_token_stream()
+        };
+        let post_op = match self.post.as_ref() {
+            Some(op) => quote!{#op;}.to_token_stream(),
+            None => quote!{}.to_token_stream()
+        };
+        let kind_fns = match kind {
+            CollectionKind::List(item_type) => {
+                let push_fn_name = format_ident!("push_{}", singular);
+                quote!{
+                    pub fn #target_field(&self) -> &'_ [#item_type] {
+                        &self.#target_field
+                    }
+                    
+                    pub fn #iter_fn_name(&self) -> impl core::iter::Iterator<Item = &'_ #item_type> + '_ {
+                        self.#target_field.iter()
+                    }
+                    
+                    pub fn #push_fn_name(&mut self, x: #item_type) {
+                        #pre_op
+                        self.#target_field.push(x);
+                        #post_op
+                    }
+                    
+                    pub fn #remove_fn_name(&mut self, index: usize) -> #item_type {
+                        #pre_op
+                        let removed = self.#target_field.remove(index);
+                        #post_op
+                        removed
+                    }
+                }
+            },
+            CollectionKind::Map(key_type, value_type) => {
+                let insert_fn_name = format_ident!("insert_{}", singular);
+                let get_fn_name = format_ident!("get_{}", singular);
+                quote!{
+                    pub fn #target_field(&self) -> &'_ #parent_type {
+                        &self.#target_field
+                    }
+                    
+                    pub fn #iter_fn_name(&self) -> impl core::iter::Iterator<Item = (&'_ #key_type, &'_ #value_type)> + '_ {
+                        self.#target_field.iter()
+                    }
+                    
+                    pub fn #insert_fn_name(&mut self, key: #key_type, value: #value_type) -> core::option::Option<#value_type> {
+                        #pre_op
+                        let replaced = self.#target_field.insert(key, value);
+                        #post_op
+                        replaced
+                    }
+                    
+                    pub fn #get_fn_name(&self, key: &#key_type) -> core::option::Option<&'_ #value_type> {
+                        self.#target_field.get(key)
+                    }
+                    
+                    pub fn #remove_fn_name(&mut self, key: &#key_type) -> core::option::Option<#value_type> {
+                        #pre_op
+                        let removed = self.#target_field.remove(key);
+                        #post_op
+                        removed
+                    }
+                }
+            },
+            CollectionKind::Set(item_type) => {
+                let insert_fn_name = format_ident!("insert_{}", singular);
+                let contains_fn_name = format_ident!("contains_{}", singular);
+                quote!{
+                    pub fn #target_field(&self) -> &'_ #parent_type {
+                        &self.#target_field
+                    }
+                    
+                    pub fn #iter_fn_name(&self) -> impl core::iter::Iterator<Item = &'_ #item_type> + '_ {
+                        self.#target_field.iter()
+                    }
+                    
+                    pub fn #insert_fn_name(&mut self, x: #item_type) -> bool {
+                        #pre_op
+                        let inserted = self.#target_field.insert(x);
+                        #post_op
+                        inserted
+                    }
+                    
+                    pub fn #contains_fn_name(&self, x: &#item_type) -> bool {
+                        self.#target_field.contains(x)
+                    }
+                    
+                    pub fn #remove_fn_name(&mut self, x: &#item_type) -> bool {
+                        #pre_op
+                        let removed = self.#target_field.remove(x);
+                        #post_op
+                        removed
+                    }
+                }
+            },
+        };
+        Ok(quote!{
+            #kind_fns
+            
+            pub fn #clear_fn_name(&mut self) {
+                #pre_op
+                self.#target_field.clear();
+                #post_op
+            }
+        })
+    }
+    
+    fn singular_of(plural: &str) -> String {
+        // plurals which add "es" to the singular, like statuses, addresses, boxes, matches and wishes
+        const ES_ENDINGS: [&str; 6] = ["sses", "tuses", "xes", "zzes", "ches", "shes"];
+        // common words which end like the rules below, but only add "s" to the singular
+        const S_WORDS: [&str; 20] = [
+            "aches", "avalanches", "caches", "cliches", "headaches", "moustaches", "niches", "quiches",
+            "brownies", "calories", "cookies", "genies", "hoodies", "lies", "movies", "pies", "rookies", "selfies", "ties", "zombies",
+        ];
+        let last_word = plural.rsplit('_').next().unwrap_or(plural);
+        if S_WORDS.contains(&last_word) {
+            plural[..plural.len() - 1].to_string()
+        } else if let Some(stem) = plural.strip_suffix("ies") {
+            format!("{}y", stem)
+        } else if ES_ENDINGS.iter().any(|ending| plural.len() > ending.len() && plural.ends_with(ending)) {
+            plural[..plural.len() - 2].to_string()
+        } else if let Some(stem) = plural.strip_suffix('s').filter(|stem| !stem.is_empty() && !stem.ends_with('s')) {
+            stem.to_string()
+        } else {
+            plural.to_string()
+        }
+    }
+    
+    #[inline]
+    fn singular_to_string(rhs: &Expr) -> Result<String> {
+        match rhs {
+            Expr::Lit(lit) => {
+                match &lit.lit {
+                    Lit::Str(lit_str) => Ok(lit_str.value()),
+                    _ => Err(Error::new_spanned(lit, format!("Invalid literal in right hand side of singular parameter #[roopert(collection, singular = {})]", lit.to_token_stream())))
+                }
+            },
+            _ => Err(Error::new_spanned(rhs, format!("Unrecognised right hand side of singular parameter #[roopert(collection, singular = {})]", rhs.to_token_stream())))
+        }
+    }
+}
+
+impl Parse for CollectionAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut pre_effect = None;
+        let mut post_effect = None;
+        let mut singular = None;
+        let mut skip = false;
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(collection, ...)]: {}", e)))?;
+        for param in params.iter() {
+            match param {
+                Expr::Assign(assign) => {
+                    if let Expr::Path(var) = &*assign.left {
+                        let ident = single_path_segment(&var.path, collection_lhs_err)?;
+                        match &ident.to_string().to_lowercase() as &str {
+                            "pre" => {
+                                pre_effect = Some((*assign.right).clone());
+                                Ok(())
+                            },
+                            "post" => {
+                                post_effect = Some((*assign.right).clone());
+                                Ok(())
+                            },
+                            "singular" => {
+                                singular = Some(Self::singular_to_string(&assign.right)?);
+                                Ok(())
+                            }
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(collection, ...)]", ident)))
+                        }
+                    } else {
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(collection, ...)]", assign.to_token_stream())))
+                    }
+                },
+                Expr::Path(var) => {
+                    let ident = single_path_segment(&var.path, collection_lhs_err)?;
+                    match &ident.to_string().to_lowercase() as &str {
+                        "skip" => {
+                            skip = true;
+                            Ok(())
+                        },
+                        _ => Err(Error::new_spanned(&ident, format!("Unrecognised attribute parameter {} in #[roopert(collection, ...)]", ident)))
+                    }
+                },
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(collection, ...)]", param.to_token_stream())))
+            }?;
+        }
+        Ok(Self {
+            pre: pre_effect,
+            post: post_effect,
+            singular,
+            skip,
+        })
+    }
+}
+
+impl Generate for CollectionAttribute {
+    fn generate(&mut self, _input: TokenStream) -> Result<TokenStream> {
+        Ok(quote!{})
+    }
+    
+    fn auto_append(&self) -> bool {true}
+}
+
+fn collection_lhs_err(path: &Path) -> String {
+    format!("Unrecognised left hand side of assignment {} in #[roopert(collection, ...)]", path.to_token_stream())
+}
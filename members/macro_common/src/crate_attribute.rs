@@ -0,0 +1,89 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{Attribute, Item, LitStr, Path, Result, Token, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, ToTokens};
+
+use super::{Generate, CratePath, RoopertAttribute, RoopertAttributeType};
+
+use super::parse::is_roopert_attribute;
+
+/// Path of the roopert crate for every roopert attribute of an item, from a `#[roopert(crate = "...")]` attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct CrateAttribute {
+    lit: LitStr,
+    path: Path,
+}
+
+impl CrateAttribute {
+    /// Uses the path of the roopert crate for the other roopert attributes of the item
+    pub fn new(path: Path) -> Self {
+        Self {
+            lit: LitStr::new(&path.to_token_stream().to_string(), Span::call_site()),
+            path,
+        }
+    }
+    
+    /// Path of the roopert crate
+    pub fn crate_path(&self) -> CratePath {
+        CratePath::new(self.path.clone())
+    }
+    
+    fn item_attrs(item: &mut Item) -> Option<&mut Vec<Attribute>> {
+        match item {
+            Item::Struct(item) => Some(&mut item.attrs),
+            Item::Enum(item) => Some(&mut item.attrs),
+            Item::Union(item) => Some(&mut item.attrs),
+            Item::Trait(item) => Some(&mut item.attrs),
+            Item::Impl(item) => Some(&mut item.attrs),
+            Item::Fn(item) => Some(&mut item.attrs),
+            _ => None,
+        }
+    }
+    
+    /// Crate path declared by a `#[roopert(crate = "...")]` attribute of the item which has not been expanded yet
+    pub fn declared_crate_path(input: &TokenStream) -> Option<CratePath> {
+        let mut item: Item = syn::parse2(input.clone()).ok()?;
+        Self::item_attrs(&mut item)?.iter()
+            .filter(|attr| is_roopert_attribute(attr))
+            .find_map(|attr| match attr.parse_args::<RoopertAttribute>() {
+                Ok(RoopertAttribute { attr: RoopertAttributeType::Crate(crate_attr), .. }) => Some(crate_attr.crate_path()),
+                _ => None,
+            })
+    }
+}
+
+impl Parse for CrateAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let _: Token![=] = input.parse().map_err(|e| Error::new(e.span(), format!("Expected crate = \"path\" in #[roopert(crate = ...)]: {}", e)))?;
+        let lit: LitStr = input.parse().map_err(|e| Error::new(e.span(), format!("Expected string literal in #[roopert(crate = ...)]: {}", e)))?;
+        let path: Path = lit.parse().map_err(|e| Error::new(e.span(), format!("Invalid path \"{}\" in #[roopert(crate = ...)]: {}", lit.value(), e)))?;
+        Ok(Self {
+            lit,
+            path,
+        })
+    }
+}
+
+impl Generate for CrateAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let mut item: Item = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only structs, enums, unions, traits, impl blocks and functions can have a roopert crate path"))?;
+        let attrs = Self::item_attrs(&mut item)
+            .ok_or_else(|| Error::new(Span::call_site(), "Only structs, enums, unions, traits, impl blocks and functions can have a roopert crate path"))?;
+        // roopert attributes below this one are expanded later, so they get the crate path as a parameter
+        let lit = &self.lit;
+        for attr in attrs.iter_mut().filter(|attr| is_roopert_attribute(attr)) {
+            let params: TokenStream = attr.parse_args().map_err(|e| Error::new(e.span(), format!("Malformed #[roopert(...)] attribute: {}", e)))?;
+            let has_crate_path = params.clone().into_iter().next()
+                .map(|token| token.to_string() == "crate")
+                .unwrap_or(false);
+            if !has_crate_path {
+                attr.tokens = quote!{(crate = #lit, #params)};
+            }
+        }
+        Ok(item.to_token_stream())
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
@@ -0,0 +1,39 @@
+use proc_macro2::{TokenStream};
+
+use syn::Path;
+
+use quote::{quote, ToTokens};
+
+/// Path of the roopert facade crate in generated code, `::roopert` by default
+#[cfg_attr(feature="verbose", derive(Debug))]
+#[derive(Clone)]
+pub struct CratePath {
+    path: Path,
+}
+
+impl CratePath {
+    /// Path of a crate which is, or re-exports, the roopert crate
+    pub fn new(path: Path) -> Self {
+        Self {
+            path,
+        }
+    }
+    
+    /// Path of the alloc crate re-exported by the facade, so generated code also works in no_std crates
+    pub fn alloc(&self) -> TokenStream {
+        let path = &self.path;
+        quote!{#path::__private::alloc}
+    }
+}
+
+impl Default for CratePath {
+    fn default() -> Self {
+        Self::new(syn::parse_quote!{::roopert})
+    }
+}
+
+impl ToTokens for CratePath {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens)
+    }
+}
@@ -0,0 +1,69 @@
+use syn::{ItemTrait, Result, Error};
+use syn::parse::{Parse, ParseStream};
+
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use quote::{quote, format_ident, ToTokens};
+
+use proc_macro2::{TokenStream, TokenTree, Span};
+
+use super::{Generate, CratePath};
+
+/// Trait which can be delegated, from a `#[roopert(delegatable)]` attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+#[derive(Default)]
+pub struct DelegatableAttribute {
+    crate_path: CratePath,
+}
+
+impl Parse for DelegatableAttribute {
+    fn parse(_input: ParseStream) -> Result<Self> {
+        Ok(Self{
+            crate_path: CratePath::default(),
+        })
+    }
+}
+
+impl Generate for DelegatableAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let target_trait: ItemTrait = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only traits can be delegatable"))?;
+        let trait_ident = &target_trait.ident;
+        let trait_vis = &target_trait.vis;
+        // macros used by other crates must be exported from the crate root, so the name includes a hash of the trait definition to be unique in the crate
+        let mut hasher = DefaultHasher::new();
+        target_trait.to_token_stream().to_string().hash(&mut hasher);
+        let macro_ident = format_ident!("__roopert_delegate_{}_{:016x}", trait_ident, hasher.finish());
+        // a path relative to the crate must still refer to this crate when the macro is used by another crate
+        let roopert: TokenStream = self.crate_path.to_token_stream().into_iter()
+            .enumerate()
+            .flat_map(|(i, token)| match token {
+                TokenTree::Ident(ident) if i == 0 && ident == "crate" => quote!{$crate},
+                token => token.into_token_stream(),
+            })
+            .collect();
+        // the macro is re-exported with the name of the trait (in the macro namespace), so importing the trait also imports the macro
+        Ok(quote!{
+            #target_trait
+            
+            #[doc(hidden)]
+            #[macro_export]
+            macro_rules! #macro_ident {
+                ($($params:tt)*) => {
+                    #[#roopert::roopert(delegated, $($params)*)]
+                    #target_trait
+                };
+            }
+            
+            #[doc(hidden)]
+            #[allow(unused_imports)]
+            #trait_vis use #macro_ident as #trait_ident;
+        })
+    }
+    
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
@@ -0,0 +1,174 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemStruct, ItemTrait, Ident, Result, Token, Path, PathArguments, Type, punctuated::Punctuated, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, ToTokens};
+
+use super::{Generate, DelegatedAttribute};
+use super::delegated_attribute::DelegationTarget;
+
+#[cfg_attr(feature="verbose", derive(Debug))]
+struct Delegation {
+    trait_path: Path,
+    field: Ident,
+}
+
+impl Parse for Delegation {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let trait_path: Path = input.parse().map_err(|e| Error::new(e.span(), format!("Expected trait in #[roopert(delegate(Trait => field))]: {}", e)))?;
+        let _: Token![=>] = input.parse().map_err(|e| Error::new(e.span(), format!("Expected => after trait {} in #[roopert(delegate(Trait => field))]: {}", trait_path.to_token_stream(), e)))?;
+        let field: Ident = input.parse().map_err(|e| Error::new(e.span(), format!("Expected field after {} => in #[roopert(delegate(Trait => field))]: {}", trait_path.to_token_stream(), e)))?;
+        Ok(Self {
+            trait_path,
+            field,
+        })
+    }
+}
+
+/// Trait implementations which forward to fields of a struct, from a `#[roopert(delegate(Trait => field, ...))]` attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct DelegateAttribute {
+    delegations: Punctuated<Delegation, Token![,]>,
+}
+
+impl DelegateAttribute {
+    /// Delegates no trait
+    pub fn new() -> Self {
+        Self {
+            delegations: Punctuated::new(),
+        }
+    }
+    
+    /// Delegates the trait to the field (`Trait => field`)
+    pub fn with_delegation(mut self, trait_path: Path, field: Ident) -> Self {
+        self.delegations.push(Delegation {
+            trait_path,
+            field,
+        });
+        self
+    }
+    
+    /// Standard library path of a trait which can be delegated without a `#[roopert(delegatable)]` definition.
+    /// Only fully qualified paths (like `std::fmt::Display` or `core::fmt::Write`) are recognised,
+    /// since a trait's name alone does not say which trait it is (`Write` could be `core::fmt::Write`, `std::io::Write` or a trait of the crate).
+    pub fn std_trait_path(trait_path: &Path) -> Option<Path> {
+        let segments: Vec<String> = trait_path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+        let segments: Vec<&str> = segments.iter().map(|segment| segment.as_str()).collect();
+        let std_path = match segments.as_slice() {
+            ["core" | "alloc" | "std", "fmt", "Display"] => quote!{core::fmt::Display},
+            ["core" | "alloc" | "std", "fmt", "Debug"] => quote!{core::fmt::Debug},
+            ["core" | "alloc" | "std", "fmt", "Write"] => quote!{core::fmt::Write},
+            ["core" | "std", "iter", "Iterator"] => quote!{core::iter::Iterator},
+            ["std", "io", "Write"] => quote!{std::io::Write},
+            ["std", "io", "Read"] => quote!{std::io::Read},
+            _ => return None,
+        };
+        Some(syn::parse2(std_path).unwrap())
+    }
+    
+    /// Definition of a standard library trait which can be delegated without a `#[roopert(delegatable)]` definition,
+    /// for the paths recognised by [`std_trait_path`](DelegateAttribute::std_trait_path)
+    pub fn builtin_trait(trait_path: &Path) -> Option<ItemTrait> {
+        let std_path = Self::std_trait_path(trait_path)?;
+        let segments: Vec<String> = std_path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+        let segments: Vec<&str> = segments.iter().map(|segment| segment.as_str()).collect();
+        let trait_ident = &trait_path.segments.last()?.ident;
+        let definition = match segments.as_slice() {
+            ["core", "fmt", "Display" | "Debug"] => quote!{
+                trait #trait_ident {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result;
+                }
+            },
+            ["core", "iter", "Iterator"] => quote!{
+                trait #trait_ident {
+                    type Item;
+                    fn next(&mut self) -> core::option::Option<Self::Item>;
+                    fn size_hint(&self) -> (usize, core::option::Option<usize>);
+                }
+            },
+            ["core", "fmt", "Write"] => quote!{
+                trait #trait_ident {
+                    fn write_str(&mut self, s: &str) -> core::fmt::Result;
+                    fn write_char(&mut self, c: char) -> core::fmt::Result;
+                }
+            },
+            ["std", "io", "Write"] => quote!{
+                trait #trait_ident {
+                    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize>;
+                    fn flush(&mut self) -> std::io::Result<()>;
+                    fn write_all(&mut self, buf: &[u8]) -> std::io::Result<()>;
+                }
+            },
+            ["std", "io", "Read"] => quote!{
+                trait #trait_ident {
+                    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize>;
+                    fn read_to_end(&mut self, buf: &mut std::vec::Vec<u8>) -> std::io::Result<usize>;
+                    fn read_to_string(&mut self, buf: &mut std::string::String) -> std::io::Result<usize>;
+                    fn read_exact(&mut self, buf: &mut [u8]) -> std::io::Result<()>;
+                }
+            },
+            _ => return None,
+        };
+        Some(syn::parse2(definition).unwrap())
+    }
+}
+
+impl Default for DelegateAttribute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parse for DelegateAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // both #[roopert(delegate(Trait => field))] and #[roopert(delegate, Trait => field)] are accepted
+        let (delegations, span) = if input.peek(syn::token::Paren) {
+            let content;
+            let paren = syn::parenthesized!(content in input);
+            (Punctuated::<Delegation, Token![,]>::parse_terminated(&content)?, paren.span)
+        } else {
+            (Punctuated::<Delegation, Token![,]>::parse_terminated(input)?, input.span())
+        };
+        if delegations.is_empty() {
+            return Err(Error::new(span, "Expected at least one Trait => field in #[roopert(delegate(...))]"));
+        }
+        Ok(Self {
+            delegations,
+        })
+    }
+}
+
+impl Generate for DelegateAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let target_struct: ItemStruct = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only named structs objects can delegate traits"))?;
+        let target_struct_ident = &target_struct.ident;
+        let (_, ty_generics, _) = target_struct.generics.split_for_impl();
+        let target: Type = syn::parse2(quote!{#target_struct_ident #ty_generics}).unwrap();
+        
+        let mut tokens = Vec::with_capacity(self.delegations.len());
+        for delegation in self.delegations.iter() {
+            let field = target_struct.fields.iter()
+                .find(|field| field.ident.as_ref() == Some(&delegation.field))
+                .ok_or_else(|| Error::new_spanned(&delegation.field, format!("Cannot delegate trait {} to {} which is not a field of this struct", delegation.trait_path.to_token_stream(), delegation.field)))?;
+            let delegated = DelegatedAttribute::new(delegation.trait_path.clone(), DelegationTarget::Field(delegation.field.clone(), field.ty.clone()), target_struct.generics.clone(), target.clone());
+            match Self::builtin_trait(&delegation.trait_path) {
+                Some(builtin) => tokens.push(delegated.impl_delegation(&builtin)?),
+                None => {
+                    // the trait's #[roopert(delegatable)] macro generates the implementation
+                    let mut macro_path = delegation.trait_path.clone();
+                    macro_path.segments.last_mut().unwrap().arguments = PathArguments::None;
+                    let params = delegated.to_params();
+                    tokens.push(quote!{
+                        #macro_path!{#params}
+                    });
+                }
+            }
+        }
+        Ok(quote!{
+            #(#tokens)*
+        })
+    }
+    
+    fn auto_append(&self) -> bool {true}
+}
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+
+use proc_macro2::{TokenStream, TokenTree, Span};
+
+use syn::{ItemTrait, TraitItem, Ident, Result, Token, Type, Path, Generics, GenericParam, GenericArgument, PathArguments, WhereClause, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, ToTokens};
+
+use super::Generate;
+use super::methods::{ForwardedMethod, ReceiverKind};
+
+/// What a delegated trait implementation forwards to
+#[cfg_attr(feature="verbose", derive(Debug))]
+#[allow(clippy::large_enum_variant)]
+pub enum DelegationTarget {
+    /// A field of a struct, and its type
+    Field(Ident, Type),
+    /// The single field of every variant of an enum, and its type
+    Variants(Vec<(Ident, Type)>),
+}
+
+impl DelegationTarget {
+    fn types(&self) -> Vec<&Type> {
+        match self {
+            Self::Field(_, field_type) => vec![field_type],
+            Self::Variants(variants) => variants.iter().map(|(_, variant_type)| variant_type).collect(),
+        }
+    }
+}
+
+impl Parse for DelegationTarget {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Token![enum]) {
+            let _: Token![enum] = input.parse()?;
+            let content;
+            syn::braced!(content in input);
+            let mut variants = Vec::new();
+            while !content.is_empty() {
+                let variant: Ident = content.parse()?;
+                let variant_content;
+                syn::parenthesized!(variant_content in content);
+                variants.push((variant, variant_content.parse()?));
+                if content.is_empty() {
+                    break;
+                }
+                let _: Token![,] = content.parse()?;
+            }
+            Ok(Self::Variants(variants))
+        } else {
+            let field: Ident = input.parse()?;
+            let _: Token![:] = input.parse()?;
+            Ok(Self::Field(field, input.parse()?))
+        }
+    }
+}
+
+impl ToTokens for DelegationTarget {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Self::Field(field, field_type) => tokens.extend(quote!{#field: #field_type}),
+            Self::Variants(variants) => {
+                let variants = variants.iter().map(|(variant, variant_type)| quote!{#variant(#variant_type)});
+                tokens.extend(quote!{enum { #(#variants,)* }});
+            },
+        }
+    }
+}
+
+/// Trait implementation which forwards to a field or to enum variants, from a `#[roopert(delegated, ...)]` attribute on the trait definition
+///
+/// This attribute is emitted by the macros generated by `#[roopert(delegatable)]`, which re-emit the trait definition with it.
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct DelegatedAttribute {
+    trait_path: Path,
+    delegation_target: DelegationTarget,
+    generics: Generics,
+    target: Type,
+}
+
+impl DelegatedAttribute {
+    /// Implementation of the trait for the target type with the generics, which forwards to the delegation target
+    pub fn new(trait_path: Path, delegation_target: DelegationTarget, generics: Generics, target: Type) -> Self {
+        Self {
+            trait_path,
+            delegation_target,
+            generics,
+            target,
+        }
+    }
+    
+    /// Parameters of `#[roopert(delegated, ...)]`, as parsed by `DelegatedAttribute::parse`
+    pub fn to_params(&self) -> TokenStream {
+        let trait_path = &self.trait_path;
+        let delegation_target = &self.delegation_target;
+        let generics = &self.generics;
+        let where_clause = &self.generics.where_clause;
+        let target = &self.target;
+        quote!{#trait_path, #delegation_target, impl #generics for #target #where_clause}
+    }
+    
+    /// Implementation of the trait definition
+    pub fn impl_delegation(&self, target_trait: &ItemTrait) -> Result<TokenStream> {
+        let trait_ident = &target_trait.ident;
+        let mut trait_path = self.trait_path.clone();
+        let mut generics = self.generics.clone();
+        
+        // generic traits are implemented for the supplied generic arguments, or for every generic argument
+        let supplied_args: Vec<TokenStream> = match &trait_path.segments.last().unwrap().arguments {
+            PathArguments::AngleBracketed(args) => args.args.iter()
+                .filter(|arg| matches!(arg, GenericArgument::Lifetime(_) | GenericArgument::Type(_) | GenericArgument::Const(_)))
+                .map(|arg| arg.to_token_stream())
+                .collect(),
+            PathArguments::None => Vec::new(),
+            PathArguments::Parenthesized(_) => return Err(Error::new_spanned(&self.trait_path, format!("Unsupported trait {} for delegation", trait_path.to_token_stream()))),
+        };
+        let mut substitutions = HashMap::new();
+        if supplied_args.is_empty() && !target_trait.generics.params.is_empty() {
+            let (_, trait_ty_generics, _) = target_trait.generics.split_for_impl();
+            trait_path.segments.last_mut().unwrap().arguments = PathArguments::AngleBracketed(syn::parse2(trait_ty_generics.to_token_stream()).unwrap());
+            for param in target_trait.generics.params.iter() {
+                generics.params.push(param.clone());
+            }
+            if let Some(trait_where_clause) = &target_trait.generics.where_clause {
+                generics.make_where_clause().predicates.extend(trait_where_clause.predicates.iter().cloned());
+            }
+        } else if supplied_args.len() == target_trait.generics.params.len() {
+            for (param, arg) in target_trait.generics.params.iter().zip(supplied_args) {
+                let param_name = match param {
+                    GenericParam::Type(type_param) => type_param.ident.to_string(),
+                    GenericParam::Lifetime(lifetime_def) => lifetime_def.lifetime.to_string(),
+                    GenericParam::Const(const_param) => const_param.ident.to_string(),
+                };
+                substitutions.insert(param_name, arg);
+            }
+        } else {
+            return Err(Error::new_spanned(&self.trait_path, format!("Wrong number of generic arguments for delegated trait {}", trait_path.to_token_stream())));
+        }
+        
+        let target = &self.target;
+        let delegate_types = self.delegation_target.types();
+        let first_type = delegate_types.first().ok_or_else(|| Error::new_spanned(&self.trait_path, format!("Cannot delegate trait {} without any variants", trait_ident)))?;
+        let delegate_path = quote!{<#first_type as #trait_path>};
+        for delegate_type in delegate_types.iter() {
+            generics.make_where_clause().predicates.push(syn::parse2(quote!{#delegate_type: #trait_path}).unwrap());
+        }
+        
+        let mut items = Vec::with_capacity(target_trait.items.len());
+        for item in target_trait.items.iter() {
+            let item: TraitItem = syn::parse2(substitute(item.to_token_stream(), &substitutions)).map_err(|e| Error::new_spanned(&self.trait_path, format!("Unable to delegate item of trait {}: {}", trait_ident, e)))?;
+            match item {
+                TraitItem::Method(method) => {
+                    let forwarded = ForwardedMethod::from_signature(&method.sig);
+                    let method_ident = forwarded.ident();
+                    if forwarded.mentions_bare_self() {
+                        return Err(Error::new_spanned(&self.trait_path, format!("Cannot delegate method {} of trait {} which uses the Self type", method_ident, trait_ident)));
+                    }
+                    if forwarded.receiver == ReceiverKind::Other {
+                        return Err(Error::new_spanned(&self.trait_path, format!("Cannot delegate method {} of trait {} with a custom self type", method_ident, trait_ident)));
+                    }
+                    let call = match &self.delegation_target {
+                        DelegationTarget::Field(field, _) => {
+                            let receiver = match forwarded.receiver {
+                                ReceiverKind::Ref => Some(quote!{&self.#field}),
+                                ReceiverKind::RefMut => Some(quote!{&mut self.#field}),
+                                ReceiverKind::Owned => Some(quote!{self.#field}),
+                                _ => None,
+                            };
+                            forwarded.call(quote!{#delegate_path::#method_ident}, receiver)
+                        },
+                        DelegationTarget::Variants(variants) => {
+                            if forwarded.receiver == ReceiverKind::Static {
+                                return Err(Error::new_spanned(&self.trait_path, format!("Cannot dispatch method {} of trait {} without a self parameter", method_ident, trait_ident)));
+                            }
+                            // match ergonomics bind the variant's field with the same reference kind as the receiver
+                            let arms = variants.iter().map(|(variant, variant_type)| {
+                                let call = forwarded.call(quote!{<#variant_type as #trait_path>::#method_ident}, Some(quote!{inner}));
+                                quote!{Self::#variant(inner) => #call,}
+                            });
+                            quote!{
+                                match self {
+                                    #(#arms)*
+                                }
+                            }
+                        },
+                    };
+                    let sig = &forwarded.sig;
+                    items.push(quote!{
+                        #sig {
+                            #call
+                        }
+                    });
+                },
+                TraitItem::Type(assoc_type) => {
+                    let type_ident = &assoc_type.ident;
+                    if delegate_types.len() > 1 {
+                        // every variant must agree with the first variant's associated type
+                        if !assoc_type.generics.params.is_empty() {
+                            return Err(Error::new_spanned(&self.trait_path, format!("Cannot dispatch generic associated type {} of trait {}", type_ident, trait_ident)));
+                        }
+                        let mut bound_path = trait_path.clone();
+                        let binding: GenericArgument = syn::parse2(quote!{#type_ident = #delegate_path::#type_ident}).unwrap();
+                        match &mut bound_path.segments.last_mut().unwrap().arguments {
+                            PathArguments::AngleBracketed(args) => args.args.push(binding),
+                            arguments => *arguments = PathArguments::AngleBracketed(syn::parse2(quote!{<#binding>}).unwrap()),
+                        }
+                        for delegate_type in delegate_types.iter().skip(1) {
+                            generics.make_where_clause().predicates.push(syn::parse2(quote!{#delegate_type: #bound_path}).unwrap());
+                        }
+                    }
+                    let (_, type_ty_generics, type_where_clause) = assoc_type.generics.split_for_impl();
+                    let type_generics = &assoc_type.generics;
+                    items.push(quote!{
+                        type #type_ident #type_generics = #delegate_path::#type_ident #type_ty_generics #type_where_clause;
+                    });
+                },
+                TraitItem::Const(assoc_const) => {
+                    let const_ident = &assoc_const.ident;
+                    if delegate_types.len() > 1 {
+                        return Err(Error::new_spanned(&self.trait_path, format!("Cannot dispatch associated constant {} of trait {}", const_ident, trait_ident)));
+                    }
+                    let const_type = &assoc_const.ty;
+                    items.push(quote!{
+                        const #const_ident: #const_type = #delegate_path::#const_ident;
+                    });
+                },
+                _ => return Err(Error::new_spanned(&self.trait_path, format!("Unsupported item in delegated trait {}", trait_ident))),
+            }
+        }
+        let unsafety = &target_trait.unsafety;
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        Ok(quote!{
+            #unsafety impl #impl_generics #trait_path for #target #where_clause {
+                #(#items)*
+            }
+        })
+    }
+}
+
+impl Parse for DelegatedAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let trait_path: Path = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let delegation_target: DelegationTarget = input.parse()?;
+        let _: Token![,] = input.parse()?;
+        let _: Token![impl] = input.parse()?;
+        let mut generics: Generics = input.parse()?;
+        let _: Token![for] = input.parse()?;
+        let target: Type = input.parse()?;
+        generics.where_clause = input.parse::<Option<WhereClause>>()?;
+        Ok(Self {
+            trait_path,
+            delegation_target,
+            generics,
+            target,
+        })
+    }
+}
+
+impl Generate for DelegatedAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let target_trait: ItemTrait = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only traits can be delegated"))?;
+        self.impl_delegation(&target_trait)
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
+
+// replace generic parameters of the trait with the supplied generic arguments
+fn substitute(tokens: TokenStream, substitutions: &HashMap<String, TokenStream>) -> TokenStream {
+    if substitutions.is_empty() {
+        return tokens;
+    }
+    let mut output = TokenStream::new();
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(ident) => match substitutions.get(&ident.to_string()) {
+                Some(replacement) => output.extend(replacement.clone()),
+                None => output.extend(Some(TokenTree::Ident(ident))),
+            },
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                let lifetime = match tokens.peek() {
+                    Some(TokenTree::Ident(ident)) => substitutions.get(&format!("'{}", ident)),
+                    _ => None,
+                };
+                match lifetime {
+                    Some(replacement) => {
+                        tokens.next();
+                        output.extend(replacement.clone());
+                    },
+                    None => output.extend(Some(TokenTree::Punct(punct))),
+                }
+            },
+            TokenTree::Group(group) => {
+                let mut new_group = proc_macro2::Group::new(group.delimiter(), substitute(group.stream(), substitutions));
+                new_group.set_span(group.span());
+                output.extend(Some(TokenTree::Group(new_group)));
+            },
+            other => output.extend(Some(other)),
+        }
+    }
+    output
+}
@@ -0,0 +1,162 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemEnum, Fields, Ident, Result, Token, Path, PathArguments, Type, punctuated::Punctuated, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, format_ident};
+
+use super::{Generate, DelegateAttribute, DelegatedAttribute};
+use super::delegated_attribute::DelegationTarget;
+use super::methods::snake_case;
+
+/// Variant conversions and dispatched trait implementations of an enum, from a `#[roopert(dispatch, ...)]` attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct DispatchAttribute {
+    traits: Punctuated<Path, Token![,]>,
+}
+
+impl DispatchAttribute {
+    /// Only implements the variant conversions
+    pub fn new() -> Self {
+        Self {
+            traits: Punctuated::new(),
+        }
+    }
+    
+    /// Dispatches the trait to the variants
+    pub fn with_trait(mut self, trait_path: Path) -> Self {
+        self.traits.push(trait_path);
+        self
+    }
+    
+    fn impl_conversions(target_enum: &ItemEnum, variants: &[(Ident, Type)]) -> TokenStream {
+        let target_enum_ident = &target_enum.ident;
+        let vis = &target_enum.vis;
+        let (impl_generics, ty_generics, where_clause) = target_enum.generics.split_for_impl();
+        let mut tokens = Vec::with_capacity(variants.len());
+        let mut accessors = Vec::with_capacity(variants.len());
+        for (variant, variant_type) in variants.iter() {
+            let snake_variant = snake_case(&variant.to_string());
+            let as_fn = format_ident!("as_{}", snake_variant);
+            let as_mut_fn = format_ident!("as_{}_mut", snake_variant);
+            let is_fn = format_ident!("is_{}", snake_variant);
+            // conversions of bare type parameters would conflict with the standard library's reflexive From implementation
+            let is_type_param = target_enum.generics.type_params().any(|type_param| matches!(variant_type, Type::Path(type_path) if type_path.qself.is_none() && type_path.path.is_ident(&type_param.ident)));
+            if !is_type_param {
+                tokens.push(quote!{
+                    impl #impl_generics core::convert::From<#variant_type> for #target_enum_ident #ty_generics #where_clause {
+                        fn from(value: #variant_type) -> Self {
+                            Self::#variant(value)
+                        }
+                    }
+                    
+                    impl #impl_generics core::convert::TryFrom<#target_enum_ident #ty_generics> for #variant_type #where_clause {
+                        type Error = #target_enum_ident #ty_generics;
+                        
+                        #[allow(unreachable_patterns)]
+                        fn try_from(value: #target_enum_ident #ty_generics) -> core::result::Result<Self, Self::Error> {
+                            match value {
+                                #target_enum_ident::#variant(inner) => core::result::Result::Ok(inner),
+                                other => core::result::Result::Err(other),
+                            }
+                        }
+                    }
+                });
+            }
+            accessors.push(quote!{
+                #[allow(unreachable_patterns)]
+                #vis fn #as_fn(&self) -> core::option::Option<&#variant_type> {
+                    match self {
+                        Self::#variant(inner) => core::option::Option::Some(inner),
+                        _ => core::option::Option::None,
+                    }
+                }
+                
+                #[allow(unreachable_patterns)]
+                #vis fn #as_mut_fn(&mut self) -> core::option::Option<&mut #variant_type> {
+                    match self {
+                        Self::#variant(inner) => core::option::Option::Some(inner),
+                        _ => core::option::Option::None,
+                    }
+                }
+                
+                #vis fn #is_fn(&self) -> bool {
+                    matches!(self, Self::#variant(_))
+                }
+            });
+        }
+        quote!{
+            #(#tokens)*
+            
+            #[allow(dead_code)]
+            impl #impl_generics #target_enum_ident #ty_generics #where_clause {
+                #(#accessors)*
+            }
+        }
+    }
+}
+
+impl Default for DispatchAttribute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parse for DispatchAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        // both #[roopert(dispatch(Trait))] and #[roopert(dispatch, Trait)] are accepted
+        let (traits, span) = if input.peek(syn::token::Paren) {
+            let content;
+            let paren = syn::parenthesized!(content in input);
+            (Punctuated::<Path, Token![,]>::parse_terminated(&content)?, paren.span)
+        } else {
+            (Punctuated::<Path, Token![,]>::parse_terminated(input)?, input.span())
+        };
+        if traits.is_empty() {
+            return Err(Error::new(span, "Expected at least one trait in #[roopert(dispatch(...))]"));
+        }
+        Ok(Self {
+            traits,
+        })
+    }
+}
+
+impl Generate for DispatchAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let target_enum: ItemEnum = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only enums can dispatch traits"))?;
+        let target_enum_ident = &target_enum.ident;
+        let (_, ty_generics, _) = target_enum.generics.split_for_impl();
+        let target: Type = syn::parse2(quote!{#target_enum_ident #ty_generics}).unwrap();
+        
+        let mut variants = Vec::with_capacity(target_enum.variants.len());
+        for variant in target_enum.variants.iter() {
+            match &variant.fields {
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => variants.push((variant.ident.clone(), fields.unnamed[0].ty.clone())),
+                _ => return Err(Error::new_spanned(variant, format!("Cannot dispatch to variant {} which does not have exactly one unnamed field", variant.ident))),
+            }
+        }
+        
+        let mut tokens = Vec::with_capacity(self.traits.len() + 1);
+        for trait_path in self.traits.iter() {
+            let delegated = DelegatedAttribute::new(trait_path.clone(), DelegationTarget::Variants(variants.clone()), target_enum.generics.clone(), target.clone());
+            match DelegateAttribute::builtin_trait(trait_path) {
+                Some(builtin) => tokens.push(delegated.impl_delegation(&builtin)?),
+                None => {
+                    // the trait's #[roopert(delegatable)] macro generates the implementation
+                    let mut macro_path = trait_path.clone();
+                    macro_path.segments.last_mut().unwrap().arguments = PathArguments::None;
+                    let params = delegated.to_params();
+                    tokens.push(quote!{
+                        #macro_path!{#params}
+                    });
+                }
+            }
+        }
+        tokens.push(Self::impl_conversions(&target_enum, &variants));
+        Ok(quote!{
+            #(#tokens)*
+        })
+    }
+    
+    fn auto_append(&self) -> bool {true}
+}
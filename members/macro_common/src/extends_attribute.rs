@@ -1,23 +1,66 @@
 //use std::fmt::{Debug, Formatter};
 use std::collections::HashMap;
 
-use proc_macro2::{TokenStream};
+use proc_macro2::{TokenStream, Span};
 
-use syn::{ItemStruct, Ident, Result, Token, punctuated::Punctuated, Type};
+use syn::{ItemStruct, Ident, Result, Token, punctuated::Punctuated, Type, Attribute, Path, Generics, GenericParam, Error};
 use syn::parse::{Parse, ParseStream};
 
-use quote::quote;
+use quote::{quote, ToTokens};
 
-use super::{Generate, ParentAttribute, RoopertAttribute, RoopertAttributeType};
+use super::{Generate, CratePath, ParentAttribute, RoopertAttribute, RoopertAttributeType, DelegateAttribute, DelegatedAttribute};
 
+use super::delegated_attribute::DelegationTarget;
+use super::extends_ops::ExtendsOps;
 use super::parse::{is_parent_attribute, is_roopert_attribute};
 
+/// Parents of a struct, from a `#[roopert(extends, ...)]` attribute
 #[cfg_attr(feature="verbose", derive(Debug))]
 pub struct ExtendsAttribute {
     types: Punctuated<Type, Token![,]>,
+    forwards: Vec<(Type, Path)>,
+    ops: Vec<(Type, ExtendsOps)>,
+    flattened: Vec<Type>,
+    crate_path: CratePath,
 }
 
 impl ExtendsAttribute {
+    /// Extends no parent, which only implements the runtime type information of the struct
+    pub fn new() -> Self {
+        Self {
+            types: Punctuated::new(),
+            forwards: Vec::new(),
+            ops: Vec::new(),
+            flattened: Vec::new(),
+            crate_path: CratePath::default(),
+        }
+    }
+    
+    /// Extends the parent type, which must be the type of a field of the struct
+    pub fn with_parent(mut self, parent_type: Type) -> Self {
+        self.types.push(parent_type);
+        self
+    }
+    
+    /// Forwards the trait implementation of the parent type to the struct (`forward(...)`)
+    pub fn with_forward(mut self, parent_type: Type, trait_path: Path) -> Self {
+        self.forwards.push((parent_type, trait_path));
+        self
+    }
+    
+    /// Forwards the default arithmetic operators of the parent type to the struct (`ops`)
+    pub fn with_ops(mut self, parent_type: Type) -> Self {
+        self.ops.push((parent_type, ExtendsOps::with_defaults()));
+        self
+    }
+    
+    /// Flattens the parent field when (de)serializing the struct (`serde`)
+    #[cfg(feature = "serde")]
+    pub fn with_serde(mut self, parent_type: Type) -> Self {
+        self.flattened.push(parent_type);
+        self
+    }
+    
     fn impl_asref(target_struct: &ItemStruct, target_field: &Ident, parent_type: &Type) -> TokenStream {
         let target_struct_ident = &target_struct.ident;
         let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
@@ -42,6 +85,23 @@ impl ExtendsAttribute {
         }
     }
     
+    fn impl_upcast(&self, target_struct: &ItemStruct, target_field: &Ident, parent_type: &Type) -> TokenStream {
+        let roopert = &self.crate_path;
+        let target_struct_ident = &target_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        quote!{
+            impl #impl_generics #roopert::Upcast<#parent_type> for #target_struct_ident #ty_generics #where_clause {
+                fn upcast(&self) -> &#parent_type {
+                    &self.#target_field
+                }
+                
+                fn upcast_mut(&mut self) -> &mut #parent_type {
+                    &mut self.#target_field
+                }
+            }
+        }
+    }
+    
     fn impl_into(target_struct: &ItemStruct, target_field: &Ident, parent_type: &Type) -> TokenStream {
         let target_struct_ident = &target_struct.ident;
         let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
@@ -79,68 +139,394 @@ impl ExtendsAttribute {
             }
         }
     }
+    
+    fn impl_abstract_check(&self, target_struct: &ItemStruct, parent_type: &Type) -> TokenStream {
+        let roopert = &self.crate_path;
+        let target_struct_ident = &target_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        // fails to compile when the parent is a roopert abstract base and the abstract methods are not implemented
+        quote!{
+            const _: () = {
+                #[allow(dead_code)]
+                fn roopert_abstract_check #impl_generics (_child: &#target_struct_ident #ty_generics) #where_clause {
+                    use #roopert::__private::{CheckAbstract as _, CheckConcrete as _};
+                    (&#roopert::__private::AbstractCheck::<#parent_type, #target_struct_ident #ty_generics>::new()).check();
+                }
+            };
+        }
+    }
+    
+    fn impl_hierarchy(&self, target_struct: &ItemStruct, parent_types: &[&Type]) -> TokenStream {
+        let roopert = &self.crate_path;
+        let target_struct_ident = &target_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        let where_predicates = where_clause.map(|where_clause| {
+            let predicates = where_clause.predicates.iter();
+            quote!{#(#predicates,)*}
+        });
+        // parents which are not part of a roopert hierarchy are registered without parents of their own
+        quote!{
+            impl #impl_generics #roopert::Hierarchy for #target_struct_ident #ty_generics where #where_predicates #target_struct_ident #ty_generics: 'static {
+                fn type_info(&self) -> &'static #roopert::TypeInfo {
+                    <Self as #roopert::Hierarchy>::static_type_info()
+                }
+                
+                fn visit_parents(visit: &mut dyn core::ops::FnMut(&'static #roopert::TypeInfo)) {
+                    #[allow(unused_imports)]
+                    use #roopert::__private::{HierarchyTypeInfo as _, LeafTypeInfo as _};
+                    #(visit((&#roopert::__private::TypeInfoOf::<#parent_types>::new()).type_info());)*
+                }
+            }
+        }
+    }
+    
+    fn forward_generics(target_struct: &ItemStruct, param: Option<GenericParam>, bound: TokenStream) -> Generics {
+        let mut generics = target_struct.generics.clone();
+        match param {
+            Some(param @ GenericParam::Lifetime(_)) => generics.params.insert(0, param),
+            Some(param) => generics.params.push(param),
+            None => {},
+        }
+        generics.make_where_clause().predicates.push(syn::parse2(bound).unwrap());
+        generics
+    }
+    
+    fn impl_forward(target_struct: &ItemStruct, target_field: &Ident, parent_type: &Type, trait_path: &Path) -> Result<TokenStream> {
+        let target_struct_ident = &target_struct.ident;
+        let (_, ty_generics, _) = target_struct.generics.split_for_impl();
+        let trait_name = trait_path.segments.last().map(|segment| segment.ident.to_string()).unwrap_or_default();
+        let token = match &trait_name as &str {
+            "PartialEq" => {
+                let generics = Self::forward_generics(target_struct, None, quote!{#parent_type: core::cmp::PartialEq});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::cmp::PartialEq for #target_struct_ident #ty_generics #where_clause {
+                        fn eq(&self, other: &Self) -> bool {
+                            core::cmp::PartialEq::eq(&self.#target_field, &other.#target_field)
+                        }
+                    }
+                }
+            },
+            "Eq" => {
+                let generics = Self::forward_generics(target_struct, None, quote!{#parent_type: core::cmp::Eq});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::cmp::Eq for #target_struct_ident #ty_generics #where_clause {}
+                }
+            },
+            "PartialOrd" => {
+                let generics = Self::forward_generics(target_struct, None, quote!{#parent_type: core::cmp::PartialOrd});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::cmp::PartialOrd for #target_struct_ident #ty_generics #where_clause {
+                        fn partial_cmp(&self, other: &Self) -> core::option::Option<core::cmp::Ordering> {
+                            core::cmp::PartialOrd::partial_cmp(&self.#target_field, &other.#target_field)
+                        }
+                    }
+                }
+            },
+            "Ord" => {
+                let generics = Self::forward_generics(target_struct, None, quote!{#parent_type: core::cmp::Ord});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::cmp::Ord for #target_struct_ident #ty_generics #where_clause {
+                        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                            core::cmp::Ord::cmp(&self.#target_field, &other.#target_field)
+                        }
+                    }
+                }
+            },
+            "Hash" => {
+                let generics = Self::forward_generics(target_struct, None, quote!{#parent_type: core::hash::Hash});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::hash::Hash for #target_struct_ident #ty_generics #where_clause {
+                        fn hash<__H: core::hash::Hasher>(&self, state: &mut __H) {
+                            core::hash::Hash::hash(&self.#target_field, state)
+                        }
+                    }
+                }
+            },
+            "IntoIterator" => {
+                let generics = Self::forward_generics(target_struct, None, quote!{#parent_type: core::iter::IntoIterator});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                let ref_generics = Self::forward_generics(target_struct, Some(syn::parse2(quote!{'__roopert}).unwrap()), quote!{&'__roopert #parent_type: core::iter::IntoIterator});
+                let (ref_impl_generics, _, ref_where_clause) = ref_generics.split_for_impl();
+                let mut_generics = Self::forward_generics(target_struct, Some(syn::parse2(quote!{'__roopert}).unwrap()), quote!{&'__roopert mut #parent_type: core::iter::IntoIterator});
+                let (mut_impl_generics, _, mut_where_clause) = mut_generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::iter::IntoIterator for #target_struct_ident #ty_generics #where_clause {
+                        type Item = <#parent_type as core::iter::IntoIterator>::Item;
+                        type IntoIter = <#parent_type as core::iter::IntoIterator>::IntoIter;
+                        fn into_iter(self) -> Self::IntoIter {
+                            core::iter::IntoIterator::into_iter(self.#target_field)
+                        }
+                    }
+                    
+                    impl #ref_impl_generics core::iter::IntoIterator for &'__roopert #target_struct_ident #ty_generics #ref_where_clause {
+                        type Item = <&'__roopert #parent_type as core::iter::IntoIterator>::Item;
+                        type IntoIter = <&'__roopert #parent_type as core::iter::IntoIterator>::IntoIter;
+                        fn into_iter(self) -> Self::IntoIter {
+                            core::iter::IntoIterator::into_iter(&self.#target_field)
+                        }
+                    }
+                    
+                    impl #mut_impl_generics core::iter::IntoIterator for &'__roopert mut #target_struct_ident #ty_generics #mut_where_clause {
+                        type Item = <&'__roopert mut #parent_type as core::iter::IntoIterator>::Item;
+                        type IntoIter = <&'__roopert mut #parent_type as core::iter::IntoIterator>::IntoIter;
+                        fn into_iter(self) -> Self::IntoIter {
+                            core::iter::IntoIterator::into_iter(&mut self.#target_field)
+                        }
+                    }
+                }
+            },
+            "Index" => {
+                let generics = Self::forward_generics(target_struct, Some(syn::parse2(quote!{__Idx}).unwrap()), quote!{#parent_type: core::ops::Index<__Idx>});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::ops::Index<__Idx> for #target_struct_ident #ty_generics #where_clause {
+                        type Output = <#parent_type as core::ops::Index<__Idx>>::Output;
+                        fn index(&self, index: __Idx) -> &Self::Output {
+                            core::ops::Index::index(&self.#target_field, index)
+                        }
+                    }
+                }
+            },
+            "IndexMut" => {
+                let generics = Self::forward_generics(target_struct, Some(syn::parse2(quote!{__Idx}).unwrap()), quote!{#parent_type: core::ops::IndexMut<__Idx>});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::ops::IndexMut<__Idx> for #target_struct_ident #ty_generics #where_clause {
+                        fn index_mut(&mut self, index: __Idx) -> &mut Self::Output {
+                            core::ops::IndexMut::index_mut(&mut self.#target_field, index)
+                        }
+                    }
+                }
+            },
+            "Extend" => {
+                let generics = Self::forward_generics(target_struct, Some(syn::parse2(quote!{__A}).unwrap()), quote!{#parent_type: core::iter::Extend<__A>});
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+                quote!{
+                    impl #impl_generics core::iter::Extend<__A> for #target_struct_ident #ty_generics #where_clause {
+                        fn extend<__I: core::iter::IntoIterator<Item = __A>>(&mut self, iter: __I) {
+                            core::iter::Extend::extend(&mut self.#target_field, iter)
+                        }
+                    }
+                }
+            },
+            _ => {
+                // the standard library traits which can be delegated, by their full path or an unambiguous shorthand
+                let segments: Vec<String> = trait_path.segments.iter().map(|segment| segment.ident.to_string()).collect();
+                let segments: Vec<&str> = segments.iter().map(|segment| segment.as_str()).collect();
+                let full_trait_path: Path = match segments.as_slice() {
+                    ["Display"] => syn::parse2(quote!{core::fmt::Display}).unwrap(),
+                    ["Debug"] => syn::parse2(quote!{core::fmt::Debug}).unwrap(),
+                    ["Iterator"] => syn::parse2(quote!{core::iter::Iterator}).unwrap(),
+                    ["fmt", "Write"] => syn::parse2(quote!{core::fmt::Write}).unwrap(),
+                    ["io", "Write"] => syn::parse2(quote!{std::io::Write}).unwrap(),
+                    ["Read"] | ["io", "Read"] => syn::parse2(quote!{std::io::Read}).unwrap(),
+                    ["Write"] => return Err(Error::new_spanned(trait_path, "Ambiguous trait Write in #[roopert(extends, ..., forward(...))] (use fmt::Write or io::Write)")),
+                    _ => DelegateAttribute::std_trait_path(trait_path)
+                        .ok_or_else(|| Error::new_spanned(trait_path, format!("Unsupported trait {} in #[roopert(extends, ..., forward(...))]", trait_path.to_token_stream())))?,
+                };
+                let builtin = DelegateAttribute::builtin_trait(&full_trait_path).unwrap();
+                let target_type: Type = syn::parse2(quote!{#target_struct_ident #ty_generics}).unwrap();
+                DelegatedAttribute::new(full_trait_path, DelegationTarget::Field(target_field.clone(), parent_type.clone()), target_struct.generics.clone(), target_type)
+                    .impl_delegation(&builtin)?
+            }
+        };
+        Ok(token)
+    }
+}
+
+impl ExtendsAttribute {
+    /// Parent types and their fields in the struct
+    pub fn parent_fields(&self, target_struct: &ItemStruct) -> Result<Vec<(Type, Ident)>> {
+        let type_map = Self::field_type_map(target_struct)?;
+        self.types.iter()
+            .map(|parent_type| match type_map.get(parent_type) {
+                Some(x) => Ok((parent_type.clone(), x.clone())),
+                None => Err(Self::not_a_field(parent_type))
+            })
+            .collect()
+    }
+    
+    /// Parent types and their fields, declared by a `#[roopert(extends, ...)]` attribute of the struct which has not been expanded yet
+    pub fn declared_parents(target_struct: &ItemStruct) -> Result<Vec<(Type, Ident)>> {
+        let mut parents = Vec::new();
+        for (_, parsed_attr) in RoopertAttribute::declared(&target_struct.attrs)? {
+            if let RoopertAttributeType::Extends(extends) = parsed_attr.attr {
+                parents.extend(extends.parent_fields(target_struct)?);
+            }
+        }
+        Ok(parents)
+    }
+    
+    /// Parent fields which are flattened when (de)serializing the struct (`serde`)
+    pub fn flattened_fields(&self, target_struct: &ItemStruct) -> Result<Vec<Ident>> {
+        let type_map = Self::field_type_map(target_struct)?;
+        self.flattened.iter()
+            .map(|parent_type| type_map.get(parent_type).cloned().ok_or_else(|| Self::not_a_field(parent_type)))
+            .collect()
+    }
+    
+    /// Flattened parent fields, declared by a `#[roopert(extends, ...)]` attribute of the struct which has not been expanded yet
+    pub fn declared_flattened_fields(target_struct: &ItemStruct) -> Result<Vec<Ident>> {
+        let mut fields = Vec::new();
+        for (_, parsed_attr) in RoopertAttribute::declared(&target_struct.attrs)? {
+            if let RoopertAttributeType::Extends(extends) = parsed_attr.attr {
+                fields.extend(extends.flattened_fields(target_struct)?);
+            }
+        }
+        Ok(fields)
+    }
+    
+    /// Whether the field attribute is `#[parent]` or `#[roopert(parent)]`
+    pub fn is_parent_field_attribute(attr: &Attribute) -> Result<bool> {
+        if is_parent_attribute(attr) {
+            attr.parse_args::<ParentAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed roopert #[parent] attribute: {}", e)))?;
+            Ok(true)
+        } else if is_roopert_attribute(attr) {
+            let parsed_attr = attr.parse_args::<RoopertAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed #[roopert(parent)] attribute: {}", e)))?;
+            Ok(parsed_attr.attr.is_parent())
+        } else {
+            Ok(false)
+        }
+    }
+    
+    fn not_a_field(parent_type: &Type) -> Error {
+        Error::new_spanned(parent_type, format!("Cannot extend type {} which is not also a field of this struct", parent_type.to_token_stream()))
+    }
+    
+    fn field_type_map(target_struct: &ItemStruct) -> Result<HashMap<Type, Ident>> {
+        let mut type_map = HashMap::<Type, Ident>::new();
+        // associate field type with field ident if has #[roopert(parent)] or #[parent] attr
+        for field in target_struct.fields.iter() {
+            for attr in &field.attrs {
+                if Self::is_parent_field_attribute(attr)? {
+                    type_map.insert(field.ty.clone(), field.ident.clone().ok_or_else(|| Error::new_spanned(field, "Only named structs objects can be extended"))?);
+                }
+            }
+        }
+        // otherwise associate field type with the first field of that type
+        for field in target_struct.fields.iter() {
+            if !type_map.contains_key(&field.ty) {
+                type_map.insert(field.ty.clone(), field.ident.clone().ok_or_else(|| Error::new_spanned(field, "Only named structs objects can be extended"))?);
+            }
+        }
+        Ok(type_map)
+    }
+}
+
+impl Default for ExtendsAttribute {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Parse for ExtendsAttribute {
     fn parse(input: ParseStream) -> Result<Self> {
+        let mut types = Punctuated::<Type, Token![,]>::new();
+        let mut forwards = Vec::new();
+        let mut ops = Vec::new();
+        let mut flattened = Vec::new();
+        // #[roopert(extends)] without parent types only registers runtime type information
+        while !input.is_empty() {
+            let is_forward = input.peek(Ident) && input.peek2(syn::token::Paren)
+                && input.fork().parse::<Ident>().map(|ident| ident == "forward").unwrap_or(false);
+            let is_ops = !input.peek2(Token![::]) && !input.peek2(Token![<])
+                && input.fork().parse::<Ident>().map(|ident| ident == "ops").unwrap_or(false);
+            let is_serde = !input.peek2(Token![::]) && !input.peek2(Token![<])
+                && input.fork().parse::<Ident>().map(|ident| ident == "serde").unwrap_or(false);
+            if is_serde {
+                // serde flattens the preceding parent type into the struct
+                let serde_ident: Ident = input.parse()?;
+                if cfg!(not(feature = "serde")) {
+                    return Err(Error::new_spanned(&serde_ident, "serde in #[roopert(extends, ...)] requires the serde feature of roopert"));
+                }
+                let parent_type = types.last().cloned().ok_or_else(|| Error::new_spanned(&serde_ident, "serde must follow a parent type in #[roopert(extends, ...)]"))?;
+                flattened.push(parent_type);
+            } else if is_ops {
+                // ops or ops(...) applies to the preceding parent type
+                let ops_ident: Ident = input.parse()?;
+                let parent_type = types.last().cloned().ok_or_else(|| Error::new_spanned(&ops_ident, "ops must follow a parent type in #[roopert(extends, ...)]"))?;
+                if input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    ops.push((parent_type, content.parse::<ExtendsOps>()?));
+                } else {
+                    ops.push((parent_type, ExtendsOps::with_defaults()));
+                }
+            } else if is_forward {
+                // forward(Trait, ...) applies to the preceding parent type
+                let forward_ident: Ident = input.parse()?;
+                let content;
+                syn::parenthesized!(content in input);
+                let parent_type = types.last().cloned().ok_or_else(|| Error::new_spanned(&forward_ident, "forward(...) must follow a parent type in #[roopert(extends, ...)]"))?;
+                for trait_path in Punctuated::<Path, Token![,]>::parse_terminated(&content)? {
+                    forwards.push((parent_type.clone(), trait_path));
+                }
+            } else {
+                types.push(input.parse()?);
+            }
+            if input.is_empty() {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
         Ok(Self{
-            types: Punctuated::<Type, Token![,]>::parse_separated_nonempty(input)?,
+            types,
+            forwards,
+            ops,
+            flattened,
+            crate_path: CratePath::default(),
         })
     }
 }
 
 impl Generate for ExtendsAttribute {
-    fn generate(&mut self, input: TokenStream) -> core::result::Result<TokenStream, String> {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
         // parse input
-        let mut target_struct: ItemStruct = syn::parse(input.into()).map_err(|_| "Only named structs objects can be extended".to_string())?;
+        let mut target_struct: ItemStruct = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only named structs objects can be extended"))?;
         //let target_struct_ident = &target_struct.ident.clone();
-        let mut type_map = HashMap::<Type, Ident>::new(); // associate extending type to struct field
+        let type_map = Self::field_type_map(&target_struct)?; // associate extending type to struct field
+        // attributes which read the parents from this attribute must be expanded before it
+        for (attr, parsed_attr) in RoopertAttribute::declared(&target_struct.attrs)? {
+            let dependent = match parsed_attr.attr {
+                RoopertAttributeType::Builder(_) => "builder",
+                RoopertAttributeType::Reflect(_) => "reflect",
+                _ => continue,
+            };
+            return Err(Error::new_spanned(attr, format!("#[roopert({})] must be above #[roopert(extends, ...)], since it reads the parents of the struct from it", dependent)));
+        }
         
         // TODO handle unnamed fields correctly
-        //let mut fields = Vec::<Field>::with_capacity(target_struct.fields.len());
         for field in target_struct.fields.iter_mut() {
             // parent attributes must be removed after processing
             // this stores any remaining attributes (which may be used by other macros or the compiler)
             let mut new_attributes = Vec::with_capacity(field.attrs.len());
-            
-            // associate field type with field ident if has #[roopert(parent)] or #[parent] attr
             for attr in &field.attrs {
-                let is_parent_path = is_parent_attribute(attr);
-                let is_roopert_path = is_roopert_attribute(attr);
-                let mut parent_attr: Option<ParentAttribute> = None;
-                if is_parent_path {
-                    parent_attr = Some(attr.parse_args::<ParentAttribute>().map_err(|_| "Malformed roopert #[parent] attribute".to_string())?);
-                } else if is_roopert_path {
-                    let parsed_attr = attr.parse_args::<RoopertAttribute>().map_err(|_| "Malformed #[roopert(parent)] attribute".to_string())?;
-                    if !parsed_attr.attr.is_parent() { 
-                        new_attributes.push(attr.clone()); // not parent attribute, keep it
-                        continue;
-                    }
-                    parent_attr = Some(match parsed_attr.attr {
-                        RoopertAttributeType::Parent(a) => Ok(a),
-                        _ => Err("Encountered quantum superpositioned #[roopert(???)] attribute (is_extends() -> true but not Extends)".to_string())
-                    }?);
-                } else {
-                    new_attributes.push(attr.clone()); // not roopert-related attribute, keep it
-                }
-                if parent_attr.is_some() {
-                    type_map.insert(field.ty.clone(), field.ident.clone().unwrap());
-                    break;
+                if !Self::is_parent_field_attribute(attr)? {
+                    new_attributes.push(attr.clone()); // not parent attribute, keep it
                 }
             }
             field.attrs = new_attributes;
-            if !type_map.contains_key(&field.ty) {
-                type_map.insert(field.ty.clone(), field.ident.clone().unwrap());
-            }
+        }
+        // flattened parents are (de)serialized as if their fields were fields of the struct
+        for parent_type in self.flattened.iter() {
+            let target_field = type_map.get(parent_type)
+                .ok_or_else(|| Self::not_a_field(parent_type))?;
+            let field = target_struct.fields.iter_mut()
+                .find(|field| field.ident.as_ref() == Some(target_field))
+                .unwrap();
+            field.attrs.push(syn::parse_quote!{#[serde(flatten)]});
         }
         
         // generate new code
         let mut tokens = vec![quote!{#target_struct}];
         for parent_type in self.types.iter() {
-            let target_field = match type_map.get(parent_type) {
-                Some(x) => Ok(x),
-                None => Err("Cannot extend type not which is not also a field of this struct".to_string())
-            }?;
+            let target_field = type_map.get(parent_type)
+                .ok_or_else(|| Self::not_a_field(parent_type))?;
             
             // AsRef implementation
             let token = Self::impl_asref(&target_struct, target_field, parent_type);
@@ -161,11 +547,42 @@ impl Generate for ExtendsAttribute {
             // DerefMut implementation
             let token = Self::impl_derefmut(&target_struct, target_field, parent_type);
             tokens.push(token);
+            
+            // Upcast implementation
+            let token = self.impl_upcast(&target_struct, target_field, parent_type);
+            tokens.push(token);
+            
+            // abstract base check
+            let token = self.impl_abstract_check(&target_struct, parent_type);
+            tokens.push(token);
+        }
+        // runtime type information
+        let parent_types: Vec<&Type> = self.types.iter().collect();
+        tokens.push(self.impl_hierarchy(&target_struct, &parent_types));
+        for (parent_type, trait_path) in self.forwards.iter() {
+            let target_field = type_map.get(parent_type)
+                .ok_or_else(|| Self::not_a_field(parent_type))?;
+            
+            // forwarded trait implementation
+            let token = Self::impl_forward(&target_struct, target_field, parent_type, trait_path)?;
+            tokens.push(token);
+        }
+        for (parent_type, parent_ops) in self.ops.iter() {
+            let target_field = type_map.get(parent_type)
+                .ok_or_else(|| Self::not_a_field(parent_type))?;
+            
+            // operator implementations
+            let token = parent_ops.impl_ops(&target_struct, target_field, parent_type);
+            tokens.push(token);
         }
         Ok(quote!{
             #(#tokens)*
         })
     }
     
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
     fn auto_append(&self) -> bool {false}
 }
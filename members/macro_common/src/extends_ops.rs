@@ -0,0 +1,190 @@
+use proc_macro2::{TokenStream};
+
+use syn::{ItemStruct, Ident, Result, Token, punctuated::Punctuated, Expr, Path, Type, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, format_ident, ToTokens};
+
+use super::parse::single_path_segment;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OpKind {
+    Binary,
+    Unary,
+    Assign,
+}
+
+// operator trait, operator function and kind
+const OPS: &[(&str, &str, OpKind)] = &[
+    ("Add", "add", OpKind::Binary),
+    ("Sub", "sub", OpKind::Binary),
+    ("Mul", "mul", OpKind::Binary),
+    ("Div", "div", OpKind::Binary),
+    ("Rem", "rem", OpKind::Binary),
+    ("BitAnd", "bitand", OpKind::Binary),
+    ("BitOr", "bitor", OpKind::Binary),
+    ("BitXor", "bitxor", OpKind::Binary),
+    ("Shl", "shl", OpKind::Binary),
+    ("Shr", "shr", OpKind::Binary),
+    ("Neg", "neg", OpKind::Unary),
+    ("Not", "not", OpKind::Unary),
+    ("AddAssign", "add_assign", OpKind::Assign),
+    ("SubAssign", "sub_assign", OpKind::Assign),
+    ("MulAssign", "mul_assign", OpKind::Assign),
+    ("DivAssign", "div_assign", OpKind::Assign),
+    ("RemAssign", "rem_assign", OpKind::Assign),
+    ("BitAndAssign", "bitand_assign", OpKind::Assign),
+    ("BitOrAssign", "bitor_assign", OpKind::Assign),
+    ("BitXorAssign", "bitxor_assign", OpKind::Assign),
+    ("ShlAssign", "shl_assign", OpKind::Assign),
+    ("ShrAssign", "shr_assign", OpKind::Assign),
+];
+
+// operators supported by every primitive number type (Neg is not implemented by unsigned integers)
+const DEFAULT_OPS: &[&str] = &["Add", "Sub", "Mul", "Div", "Rem", "AddAssign", "SubAssign", "MulAssign", "DivAssign", "RemAssign"];
+
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct ExtendsOps {
+    ops: Vec<Ident>,
+    rest_default: bool,
+}
+
+impl ExtendsOps {
+    pub fn with_defaults() -> Self {
+        Self {
+            ops: DEFAULT_OPS.iter().map(|op| format_ident!("{}", op)).collect(),
+            rest_default: false,
+        }
+    }
+    
+    pub fn impl_ops(&self, target_struct: &ItemStruct, target_field: &Ident, parent_type: &Type) -> TokenStream {
+        let target_struct_ident = &target_struct.ident;
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        let where_predicates = where_clause.map(|where_clause| {
+            let predicates = where_clause.predicates.iter();
+            quote!{#(#predicates,)*}
+        });
+        // fields which are not the parent are taken from the left hand side or initialised with Default::default()
+        let other_fields: Vec<&Ident> = target_struct.fields.iter()
+            .filter_map(|field| field.ident.as_ref())
+            .filter(|field_ident| *field_ident != target_field)
+            .collect();
+        let rest = if other_fields.is_empty() {
+            quote!{}
+        } else if self.rest_default {
+            quote!{#(#other_fields: core::default::Default::default(),)*}
+        } else {
+            quote!{..self}
+        };
+        let mut tokens = Vec::with_capacity(self.ops.len());
+        for op in self.ops.iter() {
+            let (_, op_fn, op_kind) = OPS.iter().find(|(name, _, _)| op == name).unwrap();
+            let op_fn = format_ident!("{}", op_fn);
+            match op_kind {
+                OpKind::Binary => tokens.push(quote!{
+                    impl #impl_generics core::ops::#op for #target_struct_ident #ty_generics where #where_predicates #parent_type: core::ops::#op<Output = #parent_type> {
+                        type Output = Self;
+                        fn #op_fn(self, rhs: Self) -> Self {
+                            Self {
+                                #target_field: core::ops::#op::#op_fn(self.#target_field, rhs.#target_field),
+                                #rest
+                            }
+                        }
+                    }
+                    
+                    impl #impl_generics core::ops::#op<#parent_type> for #target_struct_ident #ty_generics where #where_predicates #parent_type: core::ops::#op<Output = #parent_type> {
+                        type Output = Self;
+                        fn #op_fn(self, rhs: #parent_type) -> Self {
+                            Self {
+                                #target_field: core::ops::#op::#op_fn(self.#target_field, rhs),
+                                #rest
+                            }
+                        }
+                    }
+                }),
+                OpKind::Unary => tokens.push(quote!{
+                    impl #impl_generics core::ops::#op for #target_struct_ident #ty_generics where #where_predicates #parent_type: core::ops::#op<Output = #parent_type> {
+                        type Output = Self;
+                        fn #op_fn(self) -> Self {
+                            Self {
+                                #target_field: core::ops::#op::#op_fn(self.#target_field),
+                                #rest
+                            }
+                        }
+                    }
+                }),
+                OpKind::Assign => tokens.push(quote!{
+                    impl #impl_generics core::ops::#op for #target_struct_ident #ty_generics where #where_predicates #parent_type: core::ops::#op {
+                        fn #op_fn(&mut self, rhs: Self) {
+                            core::ops::#op::#op_fn(&mut self.#target_field, rhs.#target_field)
+                        }
+                    }
+                    
+                    impl #impl_generics core::ops::#op<#parent_type> for #target_struct_ident #ty_generics where #where_predicates #parent_type: core::ops::#op {
+                        fn #op_fn(&mut self, rhs: #parent_type) {
+                            core::ops::#op::#op_fn(&mut self.#target_field, rhs)
+                        }
+                    }
+                }),
+            }
+        }
+        quote!{
+            #(#tokens)*
+        }
+    }
+}
+
+impl Parse for ExtendsOps {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut ops = Vec::new();
+        let mut rest_default = false;
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(extends, ..., ops(...))]: {}", e)))?;
+        for param in params.iter() {
+            match param {
+                Expr::Assign(assign) => {
+                    if let Expr::Path(var) = &*assign.left {
+                        let ident = single_path_segment(&var.path, ops_lhs_err)?;
+                        match &ident.to_string().to_lowercase() as &str {
+                            "rest" => {
+                                let rest = match &*assign.right {
+                                    Expr::Path(rest_var) => single_path_segment(&rest_var.path, ops_lhs_err)?.to_string().to_lowercase(),
+                                    _ => String::new(),
+                                };
+                                match &rest as &str {
+                                    "lhs" => rest_default = false,
+                                    "default" => rest_default = true,
+                                    _ => return Err(Error::new_spanned(&assign.right, format!("Unrecognised right hand side of rest parameter #[roopert(extends, ..., ops(rest = {}))] (use lhs or default)", assign.right.to_token_stream()))),
+                                }
+                                Ok(())
+                            },
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(extends, ..., ops(...))]", ident)))
+                        }
+                    } else {
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(extends, ..., ops(...))]", assign.to_token_stream())))
+                    }
+                },
+                Expr::Path(var) => {
+                    let ident = single_path_segment(&var.path, ops_lhs_err)?;
+                    if OPS.iter().any(|(name, _, _)| ident == name) {
+                        ops.push(ident);
+                        Ok(())
+                    } else {
+                        Err(Error::new_spanned(&ident, format!("Unsupported operator {} in #[roopert(extends, ..., ops(...))]", ident)))
+                    }
+                },
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(extends, ..., ops(...))]", param.to_token_stream())))
+            }?;
+        }
+        if ops.is_empty() {
+            ops = Self::with_defaults().ops;
+        }
+        Ok(Self {
+            ops,
+            rest_default,
+        })
+    }
+}
+
+fn ops_lhs_err(path: &Path) -> String {
+    format!("Unrecognised parameter {} in #[roopert(extends, ..., ops(...))]", path.to_token_stream())
+}
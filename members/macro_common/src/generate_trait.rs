@@ -1,11 +1,34 @@
 use proc_macro2::{TokenStream};
 
+use syn::Result;
+
+use quote::ToTokens;
+
+use super::CratePath;
+
+/// Code generation of a roopert attribute.
+///
+/// Errors are [`syn::Error`]s spanned to the offending tokens,
+/// so a proc macro can report them with [`syn::Error::to_compile_error`].
 pub trait Generate {
-    fn generate(&mut self, input: TokenStream) -> Result<TokenStream, String>;
+    /// Generates the code for the item the attribute is applied to.
+    /// Depending on [`auto_append`](Generate::auto_append), the generated code replaces the item or goes after it.
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream>;
     
+    /// Whether the generated code is appended to the item instead of replacing it.
     fn auto_append(&self) -> bool;
     
-    fn generate_auto(&mut self, input: TokenStream) -> Result<TokenStream, String> {
+    /// Sets the path of the roopert facade crate used in the generated code (`::roopert` by default).
+    // generators which refer to the roopert facade crate use the crate path
+    fn set_crate_path(&mut self, _crate_path: &CratePath) {}
+    
+    /// Generates the code for an item which has already been parsed, like a [`syn::ItemStruct`].
+    fn generate_item<T: ToTokens>(&mut self, item: &T) -> Result<TokenStream> where Self: Sized {
+        self.generate(item.to_token_stream())
+    }
+    
+    /// Generates the code for the item and returns the complete output of the attribute, like the `roopert` macro does.
+    fn generate_auto(&mut self, input: TokenStream) -> Result<TokenStream> {
         //self.generate(input)
         #[cfg(feature = "verbose")]
         println!(" --- \\/ Generating \\/ --- ");
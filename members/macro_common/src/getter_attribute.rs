@@ -1,4 +1,4 @@
-use syn::{Result, Ident, Type, Expr, punctuated::Punctuated, Token, Path, Lit};
+use syn::{Result, Ident, Type, Expr, punctuated::Punctuated, Token, Path, Lit, Error};
 use syn::parse::{Parse, ParseStream};
 
 use quote::{quote, format_ident, ToTokens};
@@ -8,27 +8,59 @@ use proc_macro2::{TokenStream};
 use super::Generate;
 use super::parse::single_path_segment;
 
+/// Getter of a field, from a `#[get(...)]` or `#[roopert(get, ...)]` field attribute
 #[cfg_attr(feature="verbose", derive(Debug))]
 pub struct GetterAttribute {
     // TODO
     pre: Option<Expr>,
     mutable: bool,
     name: Option<String>,
+    skip: bool,
 }
 
 impl GetterAttribute {
+    /// Getter named `get_{field}` which returns a shared reference
     pub fn with_accessor_defaults() -> Self {
         Self {
             pre: None,
             mutable: false,
             name: None,
+            skip: false,
         }
     }
     
+    /// Sets the name of the getter
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    
+    /// Makes the getter return a mutable reference
+    pub fn with_mutable(mut self, mutable: bool) -> Self {
+        self.mutable = mutable;
+        self
+    }
+    
+    /// Evaluates the expression at the start of the getter
+    pub fn with_pre(mut self, pre: Expr) -> Self {
+        self.pre = Some(pre);
+        self
+    }
+    
+    /// Whether the field does not have a getter
+    pub fn is_skip(&self) -> bool {
+        self.skip
+    }
+    
+    /// Name of the getter of the field
+    pub fn getter_ident(&self, target_field: &Ident) -> Ident {
+        self.name.as_ref().map(|name| format_ident!("{}", name))
+            .unwrap_or_else(|| format_ident!("get_{}", target_field))
+    }
+    
+    /// Getter method of the field, to put in an inherent impl block of the struct
     pub fn impl_get_fn(&self, target_field: &Ident, parent_type: &Type) -> TokenStream {
-        let getter_fn_name = self.name.as_ref()
-            .and_then(|name| Some(format_ident!("{}", name)))
-            .unwrap_or_else(|| format_ident!("get_{}", target_field));
+        let getter_fn_name = self.getter_ident(target_field);
         let pre_op = match self.pre.as_ref() {
             Some(op) => quote!{#op;}.to_token_stream(),
             None => quote!{}.to_token_stream()
@@ -51,7 +83,7 @@ impl GetterAttribute {
     }
     
     #[inline]
-    fn mut_to_bool(rhs: &Expr, input: ParseStream) -> Result<bool> {
+    fn mut_to_bool(rhs: &Expr) -> Result<bool> {
         match rhs {
             Expr::Lit(lit) => {
                 match &lit.lit {
@@ -62,26 +94,26 @@ impl GetterAttribute {
                         match &lit_str.value().to_lowercase() as &str {
                             "true" => Ok(true),
                             "false" => Ok(false),
-                            _ => Err(input.error(format!("Invalid string literal in right hand side of mutable parameter #[roopert(get, ... = {})]", lit.to_token_stream())))
+                            _ => Err(Error::new_spanned(lit, format!("Invalid string literal in right hand side of mutable parameter #[roopert(get, ... = {})]", lit.to_token_stream())))
                         }
                     },
-                    _ => Err(input.error(format!("Unrecognised literal type in right hand side of mutable parameter in #[roopert(get, ... = {})] (use \"true\", true, \"false\", or false)", rhs.to_token_stream())))
+                    _ => Err(Error::new_spanned(rhs, format!("Unrecognised literal type in right hand side of mutable parameter in #[roopert(get, ... = {})] (use \"true\", true, \"false\", or false)", rhs.to_token_stream())))
                 }
             },
-            _ => Err(input.error(format!("Unrecognised right hand side of mutable parameter in #[roopert(get, ... = {})] (use true or false)", rhs.to_token_stream())))
+            _ => Err(Error::new_spanned(rhs, format!("Unrecognised right hand side of mutable parameter in #[roopert(get, ... = {})] (use true or false)", rhs.to_token_stream())))
         }
     }
     
     #[inline]
-    fn name_to_string(rhs: &Expr, input: ParseStream) -> Result<String> {
+    fn name_to_string(rhs: &Expr) -> Result<String> {
         match rhs {
             Expr::Lit(lit) => {
                 match &lit.lit {
                     Lit::Str(lit_str) => Ok(lit_str.value()),
-                    _ => Err(input.error(format!("Invalid literal in right hand side of name parameter #[roopert(get, name = {})]", lit.to_token_stream())))
+                    _ => Err(Error::new_spanned(lit, format!("Invalid literal in right hand side of name parameter #[roopert(get, name = {})]", lit.to_token_stream())))
                 }
             },
-            _ => Err(input.error(format!("Unrecognised right hand side of name parameter #[roopert(get, name = {})]", rhs.to_token_stream())))
+            _ => Err(Error::new_spanned(rhs, format!("Unrecognised right hand side of name parameter #[roopert(get, name = {})]", rhs.to_token_stream())))
         }
     }
 }
@@ -91,45 +123,57 @@ impl Parse for GetterAttribute {
         let mut mutable = false;
         let mut pre_effect = None;
         let mut name = None;
-        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| input.error(format!("Invalid parameter in #[roopert(get, ...)]: {}", e)))?;
+        let mut skip = false;
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(get, ...)]: {}", e)))?;
         for param in params.iter() {
             match param {
                 Expr::Assign(assign) => {
                     if let Expr::Path(var) = &*assign.left {
-                        let ident = single_path_segment(&var.path, input, getter_lhs_err)?;
+                        let ident = single_path_segment(&var.path, getter_lhs_err)?;
                         match &ident.to_string().to_lowercase() as &str {
                             "pre" => {
-                                pre_effect = Some((&*assign.right).clone());
+                                pre_effect = Some((*assign.right).clone());
                                 Ok(())
                             },
                             "mut" | "mut_" | "mutable" => {
-                                mutable = Self::mut_to_bool(&*assign.right, input)?;
+                                mutable = Self::mut_to_bool(&assign.right)?;
                                 Ok(())
                             },
                             "name" => {
-                                name = Some(Self::name_to_string(&*assign.right, input)?);
+                                name = Some(Self::name_to_string(&assign.right)?);
                                 Ok(())
                             }
-                            _ => Err(input.error(format!("Unrecognised left hand side of assignment {} in #[roopert(get, ...)]", ident.to_string())))
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(get, ...)]", ident)))
                         }
                     } else {
-                        Err(input.error(format!("Unsupported left hand side of assignment {} in #[roopert(get, ...)]", assign.to_token_stream())))
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(get, ...)]", assign.to_token_stream())))
+                    }
+                },
+                Expr::Path(var) => {
+                    let ident = single_path_segment(&var.path, getter_lhs_err)?;
+                    match &ident.to_string().to_lowercase() as &str {
+                        "skip" => {
+                            skip = true;
+                            Ok(())
+                        },
+                        _ => Err(Error::new_spanned(&ident, format!("Unrecognised attribute parameter {} in #[roopert(get, ...)]", ident)))
                     }
                 },
-                _ => Err(input.error(format!("Unrecognised attribute parameter {} in #[roopert(get, ...)]", param.to_token_stream())))
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(get, ...)]", param.to_token_stream())))
             }?;
         }
         Ok(Self {
             pre: pre_effect,
-            mutable: mutable,
-            name: name,
+            mutable,
+            name,
+            skip,
         })
     }
 }
 
 impl Generate for GetterAttribute {
-    fn generate(&mut self, _input: TokenStream) -> core::result::Result<TokenStream, String> {
-        Ok(quote!{}.into())
+    fn generate(&mut self, _input: TokenStream) -> Result<TokenStream> {
+        Ok(quote!{})
     }
     
     fn auto_append(&self) -> bool {true}
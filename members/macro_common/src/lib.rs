@@ -1,18 +1,100 @@
 //! Attributes and functionality used in all roopert macros
+//!
+//! The generators of roopert's attributes can be reused by other proc-macro crates.
+//! Every attribute can be parsed from the parameters of its `#[roopert(...)]` attribute,
+//! or built with its constructor and `with_*` methods,
+//! then generates code for an item with [`Generate`].
+//! Errors are [`syn::Error`]s which point at the offending tokens.
+//!
+//! ```
+//! use roopert_macro_common::{ExtendsAttribute, GetterAttribute, Generate};
+//! use syn::{ItemStruct, parse_quote};
+//! use quote::quote;
+//!
+//! let dog: ItemStruct = parse_quote! {
+//!     struct Dog {
+//!         animal: Animal,
+//!         name: String,
+//!     }
+//! };
+//! // equivalent to #[roopert(extends, Animal)]
+//! let generated = ExtendsAttribute::new()
+//!     .with_parent(parse_quote!(Animal))
+//!     .generate_item(&dog)
+//!     .unwrap();
+//! assert!(generated.to_string().contains("Upcast < Animal > for Dog"));
+//!
+//! // accessors can be composed into other impl blocks
+//! let get_fn = GetterAttribute::with_accessor_defaults()
+//!     .with_name("name")
+//!     .impl_get_fn(&parse_quote!(name), &parse_quote!(String));
+//! let _impl_block = quote! {
+//!     impl Dog {
+//!         #get_fn
+//!     }
+//! };
+//!
+//! // a proc macro would return error.to_compile_error()
+//! let error = ExtendsAttribute::new()
+//!     .with_parent(parse_quote!(Cat))
+//!     .generate_item(&dog)
+//!     .unwrap_err();
+//! assert_eq!(error.to_string(), "Cannot extend type Cat which is not also a field of this struct");
+//! ```
+//!
+//! Generated code refers to the `roopert` crate through `::roopert` by default;
+//! use [`Generate::set_crate_path`] when the crate is renamed or re-exported.
+#![warn(missing_docs)]
+mod abstract_attribute;
 mod accessors_attribute;
+mod builder_attribute;
+mod class_attribute;
+mod collection_attribute;
+mod crate_attribute;
+mod crate_path;
+mod delegatable_attribute;
+mod delegate_attribute;
+mod delegated_attribute;
+mod dispatch_attribute;
 mod extends_attribute;
+mod extends_ops;
 mod generate_trait;
 mod getter_attribute;
+mod methods;
+mod new_attribute;
+mod object_trait_attribute;
+mod override_attribute;
+mod overrides_attribute;
 mod parent_attribute;
+mod properties_attribute;
+mod reflect_attribute;
 mod root_attribute;
 mod setter_attribute;
+mod skip_attribute;
 
 pub mod parse;
 
-pub use accessors_attribute::AccessorsAttribute;
+pub use abstract_attribute::AbstractAttribute;
+pub use accessors_attribute::{AccessorsAttribute, AccessorAutoRule, DeclaredAccessors};
+pub use builder_attribute::BuilderAttribute;
+pub use class_attribute::ClassAttribute;
+pub use collection_attribute::CollectionAttribute;
+pub use crate_attribute::CrateAttribute;
+pub use crate_path::CratePath;
+pub use delegatable_attribute::DelegatableAttribute;
+pub use delegate_attribute::DelegateAttribute;
+pub use delegated_attribute::{DelegatedAttribute, DelegationTarget};
+pub use dispatch_attribute::DispatchAttribute;
 pub use extends_attribute::ExtendsAttribute;
 pub use generate_trait::Generate;
 pub use getter_attribute::GetterAttribute;
+pub use new_attribute::NewAttribute;
+pub use object_trait_attribute::ObjectTraitAttribute;
+pub use override_attribute::OverrideAttribute;
+pub use overrides_attribute::OverridesAttribute;
 pub use parent_attribute::ParentAttribute;
+pub use properties_attribute::PropertiesAttribute;
+pub use reflect_attribute::{ReflectAttribute, ParentsRule};
 pub use root_attribute::{RoopertAttribute, RoopertAttributeType};
 pub use setter_attribute::SetterAttribute;
+pub use skip_attribute::SkipAttribute;
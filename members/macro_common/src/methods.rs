@@ -0,0 +1,181 @@
+use proc_macro2::{TokenStream, TokenTree};
+
+use syn::{FnArg, Ident, Pat, PatIdent, Signature, GenericParam, ReturnType};
+
+use quote::{quote, format_ident, ToTokens};
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ReceiverKind {
+    Ref,
+    RefMut,
+    Owned,
+    Other,
+    Static,
+}
+
+// a method signature with every (non-self) parameter renamed to argN so that it can be forwarded to another function
+pub struct ForwardedMethod {
+    pub sig: Signature,
+    pub receiver: ReceiverKind,
+    args: Vec<Ident>,
+}
+
+impl ForwardedMethod {
+    pub fn from_signature(sig: &Signature) -> Self {
+        let mut sig = sig.clone();
+        let mut receiver = ReceiverKind::Static;
+        let mut args = Vec::new();
+        for input in sig.inputs.iter_mut() {
+            match input {
+                FnArg::Receiver(recv) => {
+                    receiver = match (&recv.reference, &recv.mutability) {
+                        (Some(_), Some(_)) => ReceiverKind::RefMut,
+                        (Some(_), None) => ReceiverKind::Ref,
+                        (None, _) => ReceiverKind::Owned,
+                    };
+                },
+                FnArg::Typed(pat_type) => {
+                    if is_self_pat(&pat_type.pat) {
+                        // self: Box<Self> and friends
+                        receiver = ReceiverKind::Other;
+                        continue;
+                    }
+                    let arg = format_ident!("arg{}", args.len());
+                    *pat_type.pat = Pat::Ident(PatIdent {
+                        attrs: Vec::new(),
+                        by_ref: None,
+                        mutability: None,
+                        ident: arg.clone(),
+                        subpat: None,
+                    });
+                    args.push(arg);
+                }
+            }
+        }
+        Self {
+            sig,
+            receiver,
+            args,
+        }
+    }
+    
+    pub fn ident(&self) -> &Ident {
+        &self.sig.ident
+    }
+    
+    pub fn has_reference_receiver(&self) -> bool {
+        matches!(self.receiver, ReceiverKind::Ref | ReceiverKind::RefMut)
+    }
+    
+    // whether Self appears anywhere except the receiver, which changes meaning when the signature is moved into a trait
+    pub fn mentions_self(&self) -> bool {
+        let in_inputs = self.sig.inputs.iter().any(|input| match input {
+            FnArg::Typed(pat_type) => !is_self_pat(&pat_type.pat) && contains_self(pat_type.ty.to_token_stream()),
+            FnArg::Receiver(_) => false,
+        });
+        let in_output = match &self.sig.output {
+            ReturnType::Type(_, ty) => contains_self(ty.to_token_stream()),
+            ReturnType::Default => false,
+        };
+        let in_where = self.sig.generics.where_clause.as_ref()
+            .map(|where_clause| contains_self(where_clause.to_token_stream()))
+            .unwrap_or(false);
+        in_inputs || in_output || in_where
+    }
+    
+    // whether Self appears as a type anywhere except the receiver (associated items like Self::Item are allowed)
+    pub fn mentions_bare_self(&self) -> bool {
+        let in_inputs = self.sig.inputs.iter().any(|input| match input {
+            FnArg::Typed(pat_type) => !is_self_pat(&pat_type.pat) && contains_bare_self(pat_type.ty.to_token_stream()),
+            FnArg::Receiver(_) => false,
+        });
+        let in_output = match &self.sig.output {
+            ReturnType::Type(_, ty) => contains_bare_self(ty.to_token_stream()),
+            ReturnType::Default => false,
+        };
+        in_inputs || in_output
+    }
+    
+    // call function with the receiver (if any) and the forwarded arguments
+    pub fn call(&self, function: TokenStream, receiver: Option<TokenStream>) -> TokenStream {
+        let turbofish = self.turbofish();
+        let args = &self.args;
+        let receiver = receiver.map(|recv| quote!{#recv,});
+        let mut call = quote!{#function #turbofish (#receiver #(#args),*)};
+        if self.sig.asyncness.is_some() {
+            call = quote!{#call.await};
+        }
+        if self.sig.unsafety.is_some() {
+            call = quote!{unsafe { #call }};
+        }
+        call
+    }
+    
+    fn turbofish(&self) -> TokenStream {
+        // explicit generic arguments are not allowed when impl Trait is used in argument position
+        let has_impl_arg = self.sig.inputs.iter().any(|input| match input {
+            FnArg::Typed(pat_type) => contains_ident(pat_type.ty.to_token_stream(), "impl"),
+            FnArg::Receiver(_) => false,
+        });
+        let params: Vec<TokenStream> = self.sig.generics.params.iter()
+            .filter_map(|param| match param {
+                GenericParam::Type(type_param) => Some(type_param.ident.to_token_stream()),
+                GenericParam::Const(const_param) => Some(const_param.ident.to_token_stream()),
+                GenericParam::Lifetime(_) => None,
+            })
+            .collect();
+        if params.is_empty() || has_impl_arg {
+            quote!{}
+        } else {
+            quote!{::<#(#params),*>}
+        }
+    }
+}
+
+pub fn snake_case(camel: &str) -> String {
+    let mut snake = String::with_capacity(camel.len() + 4);
+    for (i, c) in camel.chars().enumerate() {
+        if c.is_uppercase() {
+            if i != 0 {
+                snake.push('_');
+            }
+            snake.extend(c.to_lowercase());
+        } else {
+            snake.push(c);
+        }
+    }
+    snake
+}
+
+fn is_self_pat(pat: &Pat) -> bool {
+    matches!(pat, Pat::Ident(pat_ident) if pat_ident.ident == "self")
+}
+
+fn contains_self(tokens: TokenStream) -> bool {
+    contains_ident(tokens, "Self")
+}
+
+fn contains_bare_self(tokens: TokenStream) -> bool {
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Ident(token_ident) if token_ident == "Self" => {
+                match tokens.peek() {
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {},
+                    _ => return true,
+                }
+            },
+            TokenTree::Group(group) if contains_bare_self(group.stream()) => return true,
+            _ => {},
+        }
+    }
+    false
+}
+
+fn contains_ident(tokens: TokenStream, ident: &str) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(token_ident) => token_ident == ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        _ => false,
+    })
+}
@@ -0,0 +1,227 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemStruct, Result, Token, punctuated::Punctuated, Expr, Path, Lit, Visibility, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, format_ident, ToTokens};
+
+use super::{Generate, RoopertAttribute, RoopertAttributeType};
+
+use super::parse::{is_new_attribute, is_roopert_attribute, single_path_segment};
+
+/// Constructor of a struct, from a `#[roopert(new, ...)]` attribute, or constructor options of a field, from a `#[new(...)]` field attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct NewAttribute {
+    name: Option<String>,
+    vis: Option<Visibility>,
+    into: bool,
+    default: bool,
+    value: Option<Expr>,
+    // parsed parameters which only apply on the struct (name, vis) or only on fields (default, value), rejected at the other level
+    struct_params: Vec<(&'static str, Expr)>,
+    field_params: Vec<(&'static str, Expr)>,
+}
+
+impl NewAttribute {
+    /// `pub fn new(...)` constructor with a parameter for every field, or a field which is a parameter of the constructor
+    pub fn with_field_defaults() -> Self {
+        Self {
+            name: None,
+            vis: None,
+            into: false,
+            default: false,
+            value: None,
+            struct_params: Vec::new(),
+            field_params: Vec::new(),
+        }
+    }
+    
+    /// Sets the name of the constructor
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    
+    /// Sets the visibility of the constructor
+    pub fn with_vis(mut self, vis: Visibility) -> Self {
+        self.vis = Some(vis);
+        self
+    }
+    
+    /// Makes the parameters accept any type which converts into the field type (`into`)
+    pub fn with_into(mut self, into: bool) -> Self {
+        self.into = into;
+        self
+    }
+    
+    /// Initializes the field with `Default::default()` instead of a parameter (`default`)
+    pub fn with_default(mut self, default: bool) -> Self {
+        self.default = default;
+        self
+    }
+    
+    /// Initializes the field with the expression instead of a parameter (`value = ...`)
+    pub fn with_value(mut self, value: Expr) -> Self {
+        self.value = Some(value);
+        self
+    }
+    
+    fn impl_new_fn(&self, target_struct: &ItemStruct, field_attrs: &[NewAttribute]) -> TokenStream {
+        let new_fn_name = self.name.as_ref().map(|name| format_ident!("{}", name))
+            .unwrap_or_else(|| format_ident!("new"));
+        let new_fn_vis = self.vis.as_ref().map(|vis| vis.to_token_stream())
+            .unwrap_or_else(|| quote!{pub});
+        let mut params = Vec::new();
+        let mut conversions = Vec::new(); // into conversions and computed values, in declaration order
+        let mut inits = Vec::new();
+        for (field, field_attr) in target_struct.fields.iter().zip(field_attrs) {
+            let field_ident = field.ident.as_ref().unwrap();
+            let field_type = &field.ty;
+            if let Some(value) = &field_attr.value {
+                conversions.push(quote!{let #field_ident: #field_type = #value;});
+                inits.push(quote!{#field_ident});
+            } else if field_attr.default {
+                inits.push(quote!{#field_ident: core::default::Default::default()});
+            } else if self.into || field_attr.into {
+                params.push(quote!{#field_ident: impl core::convert::Into<#field_type>});
+                conversions.push(quote!{let #field_ident: #field_type = core::convert::Into::into(#field_ident);});
+                inits.push(quote!{#field_ident});
+            } else {
+                params.push(quote!{#field_ident: #field_type});
+                inits.push(quote!{#field_ident});
+            }
+        }
+        quote!{
+            #new_fn_vis fn #new_fn_name(#(#params),*) -> Self {
+                #(#conversions)*
+                Self {
+                    #(#inits),*
+                }
+            }
+        }
+    }
+    
+    #[inline]
+    fn str_to_string(rhs: &Expr, ctx: &str) -> Result<String> {
+        match rhs {
+            Expr::Lit(lit) => {
+                match &lit.lit {
+                    Lit::Str(lit_str) => Ok(lit_str.value()),
+                    _ => Err(Error::new_spanned(lit, format!("Invalid literal in right hand side of {} parameter #[roopert(new, {} = {})]", ctx, ctx, lit.to_token_stream())))
+                }
+            },
+            _ => Err(Error::new_spanned(rhs, format!("Unrecognised right hand side of {} parameter #[roopert(new, {} = {})]", ctx, ctx, rhs.to_token_stream())))
+        }
+    }
+}
+
+impl Parse for NewAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut attr = Self::with_field_defaults();
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(new, ...)]: {}", e)))?;
+        for param in params.iter() {
+            match param {
+                Expr::Assign(assign) => {
+                    if let Expr::Path(var) = &*assign.left {
+                        let ident = single_path_segment(&var.path, new_lhs_err)?;
+                        match &ident.to_string().to_lowercase() as &str {
+                            "name" => {
+                                attr.name = Some(Self::str_to_string(&assign.right, "name")?);
+                                attr.struct_params.push(("name", param.clone()));
+                                Ok(())
+                            },
+                            "vis" | "visibility" => {
+                                let vis = Self::str_to_string(&assign.right, "vis")?;
+                                attr.vis = Some(syn::parse_str::<Visibility>(&vis).map_err(|e| Error::new(e.span(), format!("Invalid visibility {} in #[roopert(new, vis = ...)]: {}", vis, e)))?);
+                                attr.struct_params.push(("vis", param.clone()));
+                                Ok(())
+                            },
+                            "value" => {
+                                attr.value = Some((*assign.right).clone());
+                                attr.field_params.push(("value", param.clone()));
+                                Ok(())
+                            },
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(new, ...)]", ident)))
+                        }
+                    } else {
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(new, ...)]", assign.to_token_stream())))
+                    }
+                },
+                Expr::Path(var) => {
+                    let ident = single_path_segment(&var.path, new_lhs_err)?;
+                    match &ident.to_string().to_lowercase() as &str {
+                        "into" => {
+                            attr.into = true;
+                            Ok(())
+                        },
+                        "default" => {
+                            attr.default = true;
+                            attr.field_params.push(("default", param.clone()));
+                            Ok(())
+                        },
+                        _ => Err(Error::new_spanned(&ident, format!("Unrecognised attribute parameter {} in #[roopert(new, ...)]", ident)))
+                    }
+                },
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(new, ...)]", param.to_token_stream())))
+            }?;
+        }
+        Ok(attr)
+    }
+}
+
+impl Generate for NewAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let mut target_struct: ItemStruct = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only named structs objects can have a roopert constructor"))?;
+        let target_struct_ident = &target_struct.ident.clone();
+        if let Some((name, param)) = self.field_params.first() {
+            return Err(Error::new_spanned(param, format!("Parameter {} of #[roopert(new, ...)] only applies to fields", name)));
+        }
+        let mut field_attrs: Vec<NewAttribute> = Vec::with_capacity(target_struct.fields.len());
+        
+        // find field-level new attributes
+        for field in target_struct.fields.iter_mut() {
+            if field.ident.is_none() {
+                return Err(Error::new_spanned(&*field, "Only named structs objects can have a roopert constructor"));
+            }
+            let mut field_attr = None;
+            
+            // new attributes must be removed after processing
+            // this stores any remaining attributes (which may be used by other macros or the compiler)
+            let mut new_attributes = Vec::with_capacity(field.attrs.len());
+            for attr in &field.attrs {
+                if is_new_attribute(attr) {
+                    field_attr = Some(attr.parse_args::<NewAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed roopert #[new] attribute: {}", e)))?);
+                } else if is_roopert_attribute(attr) {
+                    let parsed_attr = attr.parse_args::<RoopertAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed #[roopert(...)] attribute: {}", e)))?;
+                    match parsed_attr.attr {
+                        RoopertAttributeType::New(new_attr) => field_attr = Some(new_attr),
+                        _ => new_attributes.push(attr.clone()), // keep non-related roopert attribute
+                    }
+                } else {
+                    new_attributes.push(attr.clone()); // keep unrelated attribute
+                }
+            }
+            field.attrs = new_attributes;
+            if let Some((name, param)) = field_attr.as_ref().and_then(|field_attr| field_attr.struct_params.first()) {
+                return Err(Error::new_spanned(param, format!("Parameter {} of #[roopert(new, ...)] only applies to the struct", name)));
+            }
+            field_attrs.push(field_attr.unwrap_or_else(Self::with_field_defaults));
+        }
+        
+        let new_fn = self.impl_new_fn(&target_struct, &field_attrs);
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        Ok(quote!{
+            #target_struct
+            
+            impl #impl_generics #target_struct_ident #ty_generics #where_clause {
+                #new_fn
+            }
+        })
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
+
+fn new_lhs_err(path: &Path) -> String {
+    format!("Unrecognised left hand side of assignment {} in #[roopert(new, ...)]", path.to_token_stream())
+}
@@ -0,0 +1,185 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemTrait, Ident, Result, Token, Path, Type, TypeParamBound, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::quote;
+
+use super::{Generate, CratePath};
+
+/// Trait object support of a trait, from a `#[roopert(object_trait, ...)]` attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct ObjectTraitAttribute {
+    base: Option<Type>,
+    parent: Option<Path>,
+    crate_path: CratePath,
+}
+
+impl ObjectTraitAttribute {
+    /// Object trait without a base type or a parent trait
+    pub fn new() -> Self {
+        Self {
+            base: None,
+            parent: None,
+            crate_path: CratePath::default(),
+        }
+    }
+    
+    /// Sets the base type, whose state the trait objects can access (`base = ...`)
+    pub fn with_base(mut self, base: Type) -> Self {
+        self.base = Some(base);
+        self
+    }
+    
+    /// Sets the parent object trait, which the trait objects can be converted into (`parent = ...`)
+    pub fn with_parent(mut self, parent: Path) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+}
+
+impl Default for ObjectTraitAttribute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parse for ObjectTraitAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut base = None;
+        let mut parent = None;
+        // base types and parent traits may have generic arguments, which are not valid expressions
+        while !input.is_empty() {
+            let ident: Ident = input.parse().map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(object_trait, ...)]: {}", e)))?;
+            let _: Token![=] = input.parse().map_err(|e| Error::new(e.span(), format!("Expected assignment for parameter {} in #[roopert(object_trait, ...)]: {}", ident, e)))?;
+            match &ident.to_string().to_lowercase() as &str {
+                "base" => {
+                    base = Some(input.parse::<Type>().map_err(|e| Error::new(e.span(), format!("Unrecognised right hand side of base parameter #[roopert(object_trait, base = ...)]: {}", e)))?);
+                    Ok(())
+                },
+                "parent" => {
+                    parent = Some(input.parse::<Path>().map_err(|e| Error::new(e.span(), format!("Unrecognised right hand side of parent parameter #[roopert(object_trait, parent = ...)]: {}", e)))?);
+                    Ok(())
+                },
+                _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(object_trait, ...)]", ident)))
+            }?;
+            if input.is_empty() {
+                break;
+            }
+            let _: Token![,] = input.parse()?;
+        }
+        Ok(Self {
+            base,
+            parent,
+            crate_path: CratePath::default(),
+        })
+    }
+}
+
+impl Generate for ObjectTraitAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let mut target_trait: ItemTrait = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only traits can be roopert object traits"))?;
+        let roopert = &self.crate_path;
+        let alloc = self.crate_path.alloc();
+        // implementors must be part of a roopert hierarchy (with #[roopert(extends, ...)])
+        let as_any_bound: TypeParamBound = syn::parse2(quote!{#roopert::AsAny}).unwrap();
+        let hierarchy_bound: TypeParamBound = syn::parse2(quote!{#roopert::Hierarchy}).unwrap();
+        target_trait.supertraits.push(as_any_bound);
+        target_trait.supertraits.push(hierarchy_bound);
+        // implementors must extend the base type, directly or by being the base type
+        if let Some(base_type) = &self.base {
+            let upcast_bound: TypeParamBound = syn::parse2(quote!{#roopert::Upcast<#base_type>}).unwrap();
+            target_trait.supertraits.push(upcast_bound);
+        }
+        if let Some(parent_path) = &self.parent {
+            let is_supertrait = target_trait.supertraits.iter().any(|bound| matches!(bound, TypeParamBound::Trait(trait_bound) if &trait_bound.path == parent_path));
+            if !is_supertrait {
+                target_trait.supertraits.push(syn::parse2(quote!{#parent_path}).unwrap());
+            }
+        }
+        
+        let target_trait_ident = &target_trait.ident;
+        // trait objects are only Any when every type parameter is 'static
+        let mut generics = target_trait.generics.clone();
+        let type_params: Vec<Ident> = generics.type_params().map(|type_param| type_param.ident.clone()).collect();
+        for type_param in type_params {
+            generics.make_where_clause().predicates.push(syn::parse2(quote!{#type_param: 'static}).unwrap());
+        }
+        let (impl_generics, _, where_clause) = generics.split_for_impl();
+        let (_, ty_generics, _) = target_trait.generics.split_for_impl();
+        let object = quote!{dyn #target_trait_ident #ty_generics};
+        let vis = &target_trait.vis;
+        let base_methods = self.base.as_ref().map(|base_type| quote!{
+            /// Returns a reference to the shared base of the trait object
+            #vis fn as_base(&self) -> &#base_type {
+                <#object as #roopert::Upcast<#base_type>>::upcast(self)
+            }
+            
+            /// Returns a mutable reference to the shared base of the trait object
+            #vis fn as_base_mut(&mut self) -> &mut #base_type {
+                <#object as #roopert::Upcast<#base_type>>::upcast_mut(self)
+            }
+        });
+        let parent_methods = self.parent.as_ref().map(|parent_path| quote!{
+            /// Converts the trait object to a parent trait object
+            #vis fn as_dyn_parent(&self) -> &(dyn #parent_path + 'static) {
+                self
+            }
+            
+            /// Converts the trait object to a mutable parent trait object
+            #vis fn as_dyn_parent_mut(&mut self) -> &mut (dyn #parent_path + 'static) {
+                self
+            }
+            
+            #roopert::__private::with_alloc! {
+                /// Converts the boxed trait object to a boxed parent trait object
+                #vis fn into_dyn_parent(self: #alloc::boxed::Box<Self>) -> #alloc::boxed::Box<dyn #parent_path> {
+                    self
+                }
+            }
+        });
+        Ok(quote!{
+            #target_trait
+            
+            #[allow(dead_code)]
+            impl #impl_generics #object #where_clause {
+                /// Returns true if the trait object is a `__T`
+                #vis fn is<__T: #target_trait_ident #ty_generics>(&self) -> bool {
+                    <#object as #roopert::AsAny>::as_any(self).is::<__T>()
+                }
+                
+                /// Returns a reference to the trait object as a `__T`, or `None` if it is a different type
+                #vis fn downcast_ref<__T: #target_trait_ident #ty_generics>(&self) -> core::option::Option<&__T> {
+                    <#object as #roopert::AsAny>::as_any(self).downcast_ref::<__T>()
+                }
+                
+                /// Returns a mutable reference to the trait object as a `__T`, or `None` if it is a different type
+                #vis fn downcast_mut<__T: #target_trait_ident #ty_generics>(&mut self) -> core::option::Option<&mut __T> {
+                    <#object as #roopert::AsAny>::as_any_mut(self).downcast_mut::<__T>()
+                }
+                
+                // boxed trait objects are only available with the alloc feature of roopert
+                #roopert::__private::with_alloc! {
+                    /// Converts the boxed trait object into a `Box<__T>`, or returns it unchanged if it is a different type
+                    #vis fn downcast<__T: #target_trait_ident #ty_generics>(self: #alloc::boxed::Box<Self>) -> core::result::Result<#alloc::boxed::Box<__T>, #alloc::boxed::Box<Self>> {
+                        if self.is::<__T>() {
+                            core::result::Result::Ok(<#object as #roopert::AsAny>::into_any(self).downcast::<__T>().unwrap())
+                        } else {
+                            core::result::Result::Err(self)
+                        }
+                    }
+                }
+                
+                #base_methods
+                
+                #parent_methods
+            }
+        })
+    }
+    
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
@@ -0,0 +1,28 @@
+use syn::{Result};
+use syn::parse::{Parse, ParseStream};
+
+use quote::quote;
+
+use proc_macro2::{TokenStream};
+
+use super::{Generate};
+
+/// Overriding method, from a `#[roopert(override)]` attribute in a `#[roopert(overrides, ...)]` impl block
+#[cfg_attr(feature="verbose", derive(Debug))]
+#[derive(Default)]
+pub struct OverrideAttribute {
+}
+
+impl Parse for OverrideAttribute {
+    fn parse(_input: ParseStream) -> Result<Self> {
+        Ok(Self{})
+    }
+}
+
+impl Generate for OverrideAttribute {
+    fn generate(&mut self, _input: TokenStream) -> Result<TokenStream> {
+        Ok(quote!{})
+    }
+    
+    fn auto_append(&self) -> bool {true}
+}
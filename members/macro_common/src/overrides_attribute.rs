@@ -0,0 +1,154 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemImpl, ImplItem, Ident, Result, Token, Path, Type, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, format_ident};
+
+use super::{Generate, ClassAttribute, RoopertAttribute};
+use super::methods::{ForwardedMethod, ReceiverKind};
+
+use super::parse::is_roopert_attribute;
+
+/// Overridden class methods of a parent type, from a `#[roopert(overrides, ...)]` attribute on an inherent impl block
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct OverridesAttribute {
+    parent: Type,
+    class: Option<Path>,
+}
+
+impl OverridesAttribute {
+    /// Overrides the methods of the class trait generated for the parent type
+    pub fn new(parent: Type) -> Self {
+        Self {
+            parent,
+            class: None,
+        }
+    }
+    
+    /// Sets the path of the class trait, when it is not the default `{Parent}Class` trait
+    pub fn with_class(mut self, class: Path) -> Self {
+        self.class = Some(class);
+        self
+    }
+}
+
+impl Parse for OverridesAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let parent: Type = input.parse().map_err(|e| Error::new(e.span(), format!("Expected parent type in #[roopert(overrides, ...)]: {}", e)))?;
+        let mut class = None;
+        // class paths may have generic arguments, which are not valid expressions
+        while input.peek(Token![,]) {
+            let _: Token![,] = input.parse()?;
+            if input.is_empty() {
+                break;
+            }
+            let ident: Ident = input.parse().map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(overrides, ...)]: {}", e)))?;
+            let _: Token![=] = input.parse().map_err(|e| Error::new(e.span(), format!("Expected assignment for parameter {} in #[roopert(overrides, ...)]: {}", ident, e)))?;
+            match &ident.to_string().to_lowercase() as &str {
+                "class" => {
+                    class = Some(input.parse::<Path>().map_err(|e| Error::new(e.span(), format!("Unrecognised right hand side of class parameter #[roopert(overrides, class = ...)]: {}", e)))?);
+                    Ok(())
+                },
+                _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(overrides, ...)]", ident)))
+            }?;
+        }
+        Ok(Self {
+            parent,
+            class,
+        })
+    }
+}
+
+impl Generate for OverridesAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let mut target_impl: ItemImpl = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only impl blocks can override roopert class methods"))?;
+        if let Some((_, trait_path, _)) = &target_impl.trait_ {
+            return Err(Error::new_spanned(trait_path, "Only inherent impl blocks can override roopert class methods, not trait impl blocks"));
+        }
+        let parent_type = &self.parent;
+        let class_path = match &self.class {
+            Some(class_path) => class_path.clone(),
+            None => ClassAttribute::class_trait_path(parent_type)?,
+        };
+        let (as_ref_fn, as_mut_fn) = ClassAttribute::accessor_idents(parent_type)?;
+        let self_ty = target_impl.self_ty.clone();
+        
+        let mut super_fns = Vec::new();
+        let mut override_fns = Vec::new();
+        for item in target_impl.items.iter_mut() {
+            if let ImplItem::Method(method) = item {
+                // override attributes must be removed after processing
+                // this stores any remaining attributes (which may be used by other macros or the compiler)
+                let mut is_override = false;
+                let mut new_attributes = Vec::with_capacity(method.attrs.len());
+                for attr in &method.attrs {
+                    if is_roopert_attribute(attr) {
+                        let parsed_attr = attr.parse_args::<RoopertAttribute>().map_err(|e| Error::new(e.span(), format!("Malformed #[roopert(...)] attribute: {}", e)))?;
+                        if parsed_attr.attr.is_override() {
+                            is_override = true;
+                            continue;
+                        }
+                    }
+                    new_attributes.push(attr.clone());
+                }
+                method.attrs = new_attributes;
+                if !is_override {
+                    continue;
+                }
+                
+                let forwarded = ForwardedMethod::from_signature(&method.sig);
+                let parent_ref = match forwarded.receiver {
+                    ReceiverKind::Ref => quote!{core::convert::AsRef::<#parent_type>::as_ref(self)},
+                    ReceiverKind::RefMut => quote!{core::convert::AsMut::<#parent_type>::as_mut(self)},
+                    _ => return Err(Error::new_spanned(&method.sig, format!("Overridden method {} must take &self or &mut self", forwarded.ident()))),
+                };
+                // the class attribute leaves these methods out of the class trait, since it would not be object safe
+                if forwarded.mentions_self() {
+                    return Err(Error::new_spanned(&method.sig, format!("Overridden method {} cannot use Self in its signature, since class methods which use Self are not part of the class trait", forwarded.ident())));
+                }
+                let method_ident = forwarded.ident();
+                let method_vis = &method.vis;
+                let mut super_sig = forwarded.sig.clone();
+                super_sig.ident = format_ident!("super_{}", method_ident);
+                let super_call = forwarded.call(quote!{<#parent_type>::#method_ident}, Some(parent_ref));
+                super_fns.push(quote!{
+                    #[allow(dead_code)]
+                    #method_vis #super_sig {
+                        #super_call
+                    }
+                });
+                let override_call = forwarded.call(quote!{<#self_ty>::#method_ident}, Some(quote!{self}));
+                let sig = &forwarded.sig;
+                override_fns.push(quote!{
+                    #sig {
+                        #override_call
+                    }
+                });
+            }
+        }
+        
+        let (impl_generics, _ty_generics, where_clause) = target_impl.generics.split_for_impl();
+        Ok(quote!{
+            #target_impl
+            
+            impl #impl_generics #self_ty #where_clause {
+                #(#super_fns)*
+            }
+            
+            impl #impl_generics #class_path for #self_ty #where_clause {
+                fn #as_ref_fn(&self) -> &#parent_type {
+                    core::convert::AsRef::<#parent_type>::as_ref(self)
+                }
+                
+                fn #as_mut_fn(&mut self) -> &mut #parent_type {
+                    core::convert::AsMut::<#parent_type>::as_mut(self)
+                }
+                
+                #(#override_fns)*
+            }
+        })
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
@@ -7,7 +7,9 @@ use proc_macro2::{TokenStream};
 
 use super::{Generate};
 
+/// Parent field of a struct, from a `#[parent]` or `#[roopert(parent)]` field attribute
 #[cfg_attr(feature="verbose", derive(Debug))]
+#[derive(Default)]
 pub struct ParentAttribute {
 }
 
@@ -18,8 +20,8 @@ impl Parse for ParentAttribute {
 }
 
 impl Generate for ParentAttribute {
-    fn generate(&mut self, _input: TokenStream) -> core::result::Result<TokenStream, String> {
-        Ok(quote!{}.into())
+    fn generate(&mut self, _input: TokenStream) -> Result<TokenStream> {
+        Ok(quote!{})
     }
     
     fn auto_append(&self) -> bool {true}
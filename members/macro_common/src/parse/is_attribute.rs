@@ -1,24 +1,43 @@
 use syn::Attribute;
 
+/// Whether the attribute is a `#[parent]` attribute, with any path ending in its name
 pub fn is_parent_attribute(attr: &Attribute) -> bool {
     is_attribute(attr, "parent")
 }
 
+/// Whether the attribute is a `#[roopert(...)]` attribute, with any path ending in its name
 pub fn is_roopert_attribute(attr: &Attribute) -> bool {
     is_attribute(attr, "roopert")
 }
 
+/// Whether the attribute is a `#[get(...)]` attribute, with any path ending in its name
 pub fn is_getter_attribute(attr: &Attribute) -> bool {
     is_attribute(attr, "get")
 }
 
+/// Whether the attribute is a `#[set(...)]` attribute, with any path ending in its name
 pub fn is_setter_attribute(attr: &Attribute) -> bool {
     is_attribute(attr, "set")
 }
 
+/// Whether the attribute is a `#[collection(...)]` attribute, with any path ending in its name
+pub fn is_collection_attribute(attr: &Attribute) -> bool {
+    is_attribute(attr, "collection")
+}
+
+/// Whether the attribute is a `#[new(...)]` attribute, with any path ending in its name
+pub fn is_new_attribute(attr: &Attribute) -> bool {
+    is_attribute(attr, "new")
+}
+
+/// Whether the attribute is a `#[builder(...)]` attribute, with any path ending in its name
+pub fn is_builder_attribute(attr: &Attribute) -> bool {
+    is_attribute(attr, "builder")
+}
+
 fn is_attribute(attr: &Attribute, last: &str) -> bool {
     match attr.path.segments.last() {
-        Some(last_segment) => last_segment.ident.to_string() == last,
+        Some(last_segment) => last_segment.ident == last,
         None => false
     }
 }
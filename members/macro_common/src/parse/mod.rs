@@ -1,3 +1,5 @@
+//! Helpers for parsing roopert attributes
+
 mod is_attribute;
 mod path;
 
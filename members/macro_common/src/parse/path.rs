@@ -1,10 +1,11 @@
 use std::fmt::Display;
 
-use syn::{Path, parse::ParseStream, Ident, Result};
+use syn::{Path, Ident, Result, Error};
 
-pub fn single_path_segment<D: Display, E: Fn(&Path) -> D>(path: &Path, input: ParseStream, err_fn: E) -> Result<Ident> {
+/// Identifier of a path with a single segment, or a parse error built from the path by `err_fn`
+pub fn single_path_segment<D: Display, E: Fn(&Path) -> D>(path: &Path, err_fn: E) -> Result<Ident> {
     if path.segments.len() != 1 {
-        Err(input.error(err_fn(path)))
+        Err(Error::new_spanned(path, err_fn(path)))
     } else {
         Ok(path.segments.first().unwrap().clone().ident)
     }
@@ -0,0 +1,109 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemStruct, Result, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::quote;
+
+use super::{Generate, CratePath, AccessorsAttribute};
+
+/// String-keyed property access of a struct, from a `#[roopert(properties)]` attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+#[derive(Default)]
+pub struct PropertiesAttribute {
+    crate_path: CratePath,
+}
+
+impl Parse for PropertiesAttribute {
+    fn parse(_input: ParseStream) -> Result<Self> {
+        Ok(Self{
+            crate_path: CratePath::default(),
+        })
+    }
+}
+
+impl Generate for PropertiesAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let target_struct: ItemStruct = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only named structs objects can have roopert properties"))?;
+        // accessors are only known if the accessors attribute has not been expanded yet
+        let accessors = AccessorsAttribute::declared_accessors(&target_struct)?;
+        let target_struct_ident = &target_struct.ident;
+        let roopert = &self.crate_path;
+        let alloc = self.crate_path.alloc();
+        let target_name = target_struct_ident.to_string();
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        
+        let mut names = Vec::with_capacity(accessors.len());
+        let mut get_arms = Vec::with_capacity(accessors.len());
+        let mut set_arms = Vec::with_capacity(accessors.len());
+        for (field_ident, getter, setter) in accessors.iter() {
+            if getter.is_none() && setter.is_none() {
+                continue;
+            }
+            let field_type = &target_struct.fields.iter()
+                .find(|field| field.ident.as_ref() == Some(field_ident))
+                .unwrap()
+                .ty;
+            let name = field_ident.to_string();
+            names.push(name.clone());
+            // types which do not implement Display or FromStr are detected when the struct is compiled, but reported at runtime
+            get_arms.push(match getter {
+                Some(_) => quote!{
+                    #name => (&#roopert::__private::PropertyValue::<#field_type>::new()).display_property(&self.#field_ident)
+                        .ok_or(#roopert::PropertyError::Unsupported { target: #target_name, property: #name }),
+                },
+                None => quote!{
+                    #name => core::result::Result::Err(#roopert::PropertyError::WriteOnly { target: #target_name, property: #name }),
+                },
+            });
+            set_arms.push(match setter {
+                Some(setter_ident) => quote!{
+                    #name => {
+                        let parsed = (&#roopert::__private::PropertyValue::<#field_type>::new()).parse_property(value)
+                            .ok_or(#roopert::PropertyError::Unsupported { target: #target_name, property: #name })?
+                            .map_err(|message| #roopert::PropertyError::Parse { target: #target_name, property: #name, value: #alloc::string::ToString::to_string(value), message })?;
+                        self.#setter_ident(parsed);
+                        core::result::Result::Ok(())
+                    },
+                },
+                None => quote!{
+                    #name => core::result::Result::Err(#roopert::PropertyError::ReadOnly { target: #target_name, property: #name }),
+                },
+            });
+        }
+        
+        Ok(quote!{
+            #target_struct
+            
+            impl #impl_generics #roopert::Properties for #target_struct_ident #ty_generics #where_clause {
+                fn properties(&self) -> &'static [&'static str] {
+                    &[#(#names,)*]
+                }
+                
+                fn get_property(&self, name: &str) -> core::result::Result<#alloc::string::String, #roopert::PropertyError> {
+                    #[allow(unused_imports)]
+                    use #roopert::__private::{DisplayProperty as _, DisplayUnsupported as _};
+                    match name {
+                        #(#get_arms)*
+                        _ => core::result::Result::Err(#roopert::PropertyError::UnknownProperty { target: #target_name, property: #alloc::string::ToString::to_string(name) }),
+                    }
+                }
+                
+                fn set_property(&mut self, name: &str, value: &str) -> core::result::Result<(), #roopert::PropertyError> {
+                    #[allow(unused_imports)]
+                    use #roopert::__private::{ParseProperty as _, ParseUnsupported as _};
+                    match name {
+                        #(#set_arms)*
+                        _ => core::result::Result::Err(#roopert::PropertyError::UnknownProperty { target: #target_name, property: #alloc::string::ToString::to_string(name) }),
+                    }
+                }
+            }
+        })
+    }
+    
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
@@ -0,0 +1,293 @@
+use proc_macro2::{TokenStream, Span};
+
+use syn::{ItemStruct, Ident, Result, Token, punctuated::Punctuated, Expr, Path, Type, Visibility, Error};
+use syn::parse::{Parse, ParseStream};
+
+use quote::{quote, ToTokens};
+
+use super::{Generate, CratePath, AccessorsAttribute, ExtendsAttribute};
+use super::parse::single_path_segment;
+
+/// Reflection of the fields of parents
+#[cfg_attr(feature="verbose", derive(Debug))]
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum ParentsRule {
+    /// Parent fields are not reflected
+    No,
+    /// Parent fields are reflected as `parent.field`
+    Nested,
+    /// Parent fields are reflected as if they were fields of the struct
+    Flatten,
+}
+
+/// Field reflection of a struct, from a `#[roopert(reflect, ...)]` attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+pub struct ReflectAttribute {
+    parents: ParentsRule,
+    crate_path: CratePath,
+}
+
+impl ReflectAttribute {
+    /// Reflects the fields of the struct, without the fields of its parents
+    pub fn new() -> Self {
+        Self {
+            parents: ParentsRule::No,
+            crate_path: CratePath::default(),
+        }
+    }
+    
+    /// Sets how the fields of the parents are reflected (`parents = ...`)
+    pub fn with_parents(mut self, parents: ParentsRule) -> Self {
+        self.parents = parents;
+        self
+    }
+    
+    // type as written in the struct, without the spaces inserted between tokens
+    fn type_name(ty: &Type) -> String {
+        let mut name = ty.to_token_stream().to_string();
+        for (spaced, unspaced) in [(" <", "<"), ("< ", "<"), (" >", ">"), (" :: ", "::"), (":: ", "::"), (" ,", ","), ("& ", "&"), ("* ", "*"), (" ;", ";"), ("[ ", "["), (" ]", "]"), ("( ", "("), (" )", ")")] {
+            name = name.replace(spaced, unspaced);
+        }
+        name
+    }
+    
+    fn visibility(&self, vis: &Visibility) -> TokenStream {
+        let roopert = &self.crate_path;
+        match vis {
+            Visibility::Public(_) => quote!{#roopert::FieldVisibility::Public},
+            Visibility::Crate(_) => quote!{#roopert::FieldVisibility::Crate},
+            Visibility::Restricted(restricted) if restricted.in_token.is_none() && restricted.path.is_ident("crate") => quote!{#roopert::FieldVisibility::Crate},
+            Visibility::Restricted(_) => quote!{#roopert::FieldVisibility::Restricted},
+            Visibility::Inherited => quote!{#roopert::FieldVisibility::Private},
+        }
+    }
+}
+
+impl Default for ReflectAttribute {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Parse for ReflectAttribute {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut parents = ParentsRule::No;
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(reflect, ...)]: {}", e)))?;
+        for param in params.iter() {
+            match param {
+                Expr::Assign(assign) => {
+                    if let Expr::Path(var) = &*assign.left {
+                        let ident = single_path_segment(&var.path, reflect_lhs_err)?;
+                        match &ident.to_string().to_lowercase() as &str {
+                            "parents" => {
+                                let rule = match &*assign.right {
+                                    Expr::Path(rule_var) => single_path_segment(&rule_var.path, reflect_lhs_err)?.to_string().to_lowercase(),
+                                    _ => String::new(),
+                                };
+                                parents = match &rule as &str {
+                                    "no" | "none" => ParentsRule::No,
+                                    "nested" => ParentsRule::Nested,
+                                    "flatten" | "flattened" => ParentsRule::Flatten,
+                                    _ => return Err(Error::new_spanned(&assign.right, format!("Unrecognised right hand side of parents parameter #[roopert(reflect, parents = {})] (use nested, flatten or no)", assign.right.to_token_stream()))),
+                                };
+                                Ok(())
+                            },
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(reflect, ...)]", ident)))
+                        }
+                    } else {
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(reflect, ...)]", assign.to_token_stream())))
+                    }
+                },
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(reflect, ...)]", param.to_token_stream())))
+            }?;
+        }
+        Ok(Self {
+            parents,
+            crate_path: CratePath::default(),
+        })
+    }
+}
+
+impl Generate for ReflectAttribute {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let target_struct: ItemStruct = syn::parse2(input).map_err(|_| Error::new(Span::call_site(), "Only named structs objects can be reflected"))?;
+        // parents and accessors are only known if the extends and accessors attributes have not been expanded yet
+        let parents = ExtendsAttribute::declared_parents(&target_struct)?;
+        let accessors = AccessorsAttribute::declared_accessors(&target_struct)?;
+        let target_struct_ident = &target_struct.ident;
+        let roopert = &self.crate_path;
+        let alloc = self.crate_path.alloc();
+        let target_name = target_struct_ident.to_string();
+        let (impl_generics, ty_generics, where_clause) = target_struct.generics.split_for_impl();
+        let where_predicates = where_clause.map(|where_clause| {
+            let predicates = where_clause.predicates.iter();
+            quote!{#(#predicates,)*}
+        });
+        
+        let mut field_infos = Vec::with_capacity(target_struct.fields.len());
+        let mut get_arms = Vec::with_capacity(target_struct.fields.len());
+        let mut set_arms = Vec::with_capacity(target_struct.fields.len());
+        for field in target_struct.fields.iter() {
+            let field_ident = field.ident.as_ref().ok_or_else(|| Error::new_spanned(field, "Only named structs objects can be reflected"))?;
+            let field_name = field_ident.to_string();
+            let field_type = &field.ty;
+            let type_name = Self::type_name(field_type);
+            let visibility = self.visibility(&field.vis);
+            let is_parent = parents.iter().any(|(_, parent_field)| parent_field == field_ident);
+            let (has_getter, setter) = accessors.iter()
+                .find(|(accessor_field, _, _)| accessor_field == field_ident)
+                .map(|(_, getter, setter)| (getter.is_some(), setter.clone()))
+                .unwrap_or((false, None));
+            let has_setter = setter.is_some();
+            field_infos.push(quote!{
+                #roopert::FieldInfo {
+                    name: #field_name,
+                    type_name: #type_name,
+                    visibility: #visibility,
+                    is_parent: #is_parent,
+                    has_getter: #has_getter,
+                    has_setter: #has_setter,
+                }
+            });
+            // like for setting, only public fields and fields with a getter can be read
+            if has_getter || matches!(field.vis, Visibility::Public(_)) {
+                get_arms.push(quote!{
+                    #field_name => core::option::Option::Some(&self.#field_ident),
+                });
+            }
+            // setters run their hooks, public fields without a setter are assigned directly
+            let assignment = match (&setter, &field.vis) {
+                (Some(setter_ident), _) => quote!{self.#setter_ident(*value)},
+                (None, Visibility::Public(_)) => quote!{self.#field_ident = *value},
+                (None, _) => {
+                    set_arms.push(quote!{
+                        #field_name => core::result::Result::Err(#roopert::ReflectError::ReadOnly { target: #target_name, field: #field_name }),
+                    });
+                    continue;
+                },
+            };
+            set_arms.push(quote!{
+                #field_name => {
+                    let value = value.downcast::<#field_type>()
+                        .map_err(|_| #roopert::ReflectError::WrongType { target: #target_name, field: #field_name, expected: #type_name })?;
+                    #assignment;
+                    core::result::Result::Ok(())
+                },
+            });
+        }
+        
+        // parent fields are reached through the parent's Reflect implementation
+        let walked_parents: Vec<(Type, Ident)> = match self.parents {
+            ParentsRule::No => Vec::new(),
+            ParentsRule::Nested | ParentsRule::Flatten => parents,
+        };
+        let parent_bounds = walked_parents.iter().map(|(parent_type, _)| quote!{#parent_type: #roopert::Reflect,});
+        let parent_get = walked_parents.iter().map(|(_, parent_field)| match self.parents {
+            ParentsRule::Flatten => quote!{
+                if let core::option::Option::Some(value) = #roopert::Reflect::get_field(&self.#parent_field, name) {
+                    return core::option::Option::Some(value);
+                }
+            },
+            _ => {
+                let prefix = format!("{}.", parent_field);
+                quote!{
+                    if let core::option::Option::Some(path) = name.strip_prefix(#prefix) {
+                        return #roopert::Reflect::get_field(&self.#parent_field, path);
+                    }
+                }
+            },
+        });
+        let parent_set = walked_parents.iter().map(|(_, parent_field)| match self.parents {
+            ParentsRule::Flatten => quote!{
+                if #roopert::Reflect::all_fields(&self.#parent_field).iter().any(|field| field.path == name) {
+                    return #roopert::Reflect::set_field(&mut self.#parent_field, name, value);
+                }
+            },
+            _ => {
+                let prefix = format!("{}.", parent_field);
+                quote!{
+                    if let core::option::Option::Some(path) = name.strip_prefix(#prefix) {
+                        return #roopert::Reflect::set_field(&mut self.#parent_field, path, value);
+                    }
+                }
+            },
+        });
+        let parent_fields = walked_parents.iter().map(|(_, parent_field)| match self.parents {
+            ParentsRule::Flatten => quote!{
+                fields.extend(#roopert::Reflect::all_fields(&self.#parent_field));
+            },
+            _ => {
+                let parent_name = parent_field.to_string();
+                quote!{
+                    fields.extend(#roopert::Reflect::all_fields(&self.#parent_field).into_iter().map(|field| #roopert::ReflectedField {
+                        path: #alloc::format!("{}.{}", #parent_name, field.path),
+                        info: field.info,
+                    }));
+                }
+            },
+        });
+        let all_fields = if walked_parents.is_empty() {
+            None
+        } else {
+            Some(quote!{
+                fn all_fields(&self) -> #alloc::vec::Vec<#roopert::ReflectedField> {
+                    let mut fields: #alloc::vec::Vec<#roopert::ReflectedField> = Self::FIELDS.iter()
+                        .map(|info| #roopert::ReflectedField { path: #alloc::string::ToString::to_string(info.name), info: *info })
+                        .collect();
+                    #(#parent_fields)*
+                    fields
+                }
+            })
+        };
+        
+        Ok(quote!{
+            #target_struct
+            
+            impl #impl_generics #target_struct_ident #ty_generics #where_clause {
+                /// Static metadata of every field of the struct, in declaration order
+                pub const FIELDS: &'static [#roopert::FieldInfo] = &[
+                    #(#field_infos,)*
+                ];
+            }
+            
+            impl #impl_generics #roopert::Reflect for #target_struct_ident #ty_generics where #where_predicates #(#parent_bounds)* #target_struct_ident #ty_generics: 'static {
+                fn fields(&self) -> &'static [#roopert::FieldInfo] {
+                    Self::FIELDS
+                }
+                
+                #all_fields
+                
+                fn get_field(&self, name: &str) -> core::option::Option<&dyn core::any::Any> {
+                    match name {
+                        #(#get_arms)*
+                        _ => {
+                            #(#parent_get)*
+                            core::option::Option::None
+                        },
+                    }
+                }
+                
+                #[allow(unused_variables)]
+                fn set_field(&mut self, name: &str, value: #alloc::boxed::Box<dyn core::any::Any>) -> core::result::Result<(), #roopert::ReflectError> {
+                    match name {
+                        #(#set_arms)*
+                        _ => {
+                            #(#parent_set)*
+                            core::result::Result::Err(#roopert::ReflectError::UnknownField { target: #target_name, field: #alloc::string::ToString::to_string(name) })
+                        },
+                    }
+                }
+            }
+        })
+    }
+    
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        self.crate_path = crate_path.clone();
+    }
+    
+    fn auto_append(&self) -> bool {false}
+}
+
+fn reflect_lhs_err(path: &Path) -> String {
+    format!("Unrecognised parameter {} in #[roopert(reflect, ...)]", path.to_token_stream())
+}
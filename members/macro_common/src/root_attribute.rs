@@ -1,56 +1,185 @@
 use proc_macro2::TokenStream;
-use syn::{Ident, Result, Token};
+use syn::{Ident, Result, Token, Attribute, Error};
 use syn::parse::{Parse, ParseStream};
+use syn::ext::IdentExt;
 
-use super::{ParentAttribute, ExtendsAttribute, AccessorsAttribute, GetterAttribute, SetterAttribute, Generate};
+use super::parse::is_roopert_attribute;
+use super::{ParentAttribute, ExtendsAttribute, AccessorsAttribute, GetterAttribute, SetterAttribute, SkipAttribute, CollectionAttribute, NewAttribute, BuilderAttribute, ClassAttribute, OverridesAttribute, OverrideAttribute, AbstractAttribute, DelegatableAttribute, DelegateAttribute, DelegatedAttribute, ObjectTraitAttribute, DispatchAttribute, ReflectAttribute, PropertiesAttribute, CrateAttribute, CratePath, Generate};
 
+/// Type of a roopert attribute, with its parameters
 #[cfg_attr(feature="verbose", derive(Debug))]
+#[allow(clippy::large_enum_variant)]
 pub enum RoopertAttributeType {
+    /// `#[roopert(parent, ...)]`
     Parent(ParentAttribute),
+    /// `#[roopert(extends, ...)]`
     Extends(ExtendsAttribute),
+    /// `#[roopert(accessors, ...)]`
     Accessors(AccessorsAttribute),
+    /// `#[roopert(get, ...)]`
     Get(GetterAttribute),
+    /// `#[roopert(set, ...)]`
     Set(SetterAttribute),
+    /// `#[roopert(skip, ...)]`
+    Skip(SkipAttribute),
+    /// `#[roopert(collection, ...)]`
+    Collection(CollectionAttribute),
+    /// `#[roopert(new, ...)]`
+    New(NewAttribute),
+    /// `#[roopert(builder, ...)]`
+    Builder(BuilderAttribute),
+    /// `#[roopert(class, ...)]`
+    Class(ClassAttribute),
+    /// `#[roopert(overrides, ...)]`
+    Overrides(OverridesAttribute),
+    /// `#[roopert(override, ...)]`
+    Override(OverrideAttribute),
+    /// `#[roopert(abstract, ...)]`
+    Abstract(AbstractAttribute),
+    /// `#[roopert(delegatable, ...)]`
+    Delegatable(DelegatableAttribute),
+    /// `#[roopert(delegate, ...)]`
+    Delegate(DelegateAttribute),
+    /// `#[roopert(delegated, ...)]`
+    Delegated(DelegatedAttribute),
+    /// `#[roopert(object_trait, ...)]`
+    ObjectTrait(ObjectTraitAttribute),
+    /// `#[roopert(dispatch, ...)]`
+    Dispatch(DispatchAttribute),
+    /// `#[roopert(reflect, ...)]`
+    Reflect(ReflectAttribute),
+    /// `#[roopert(properties, ...)]`
+    Properties(PropertiesAttribute),
+    /// `#[roopert(crate = "...")]`
+    Crate(CrateAttribute),
 }
 
 impl RoopertAttributeType {
+    /// Whether this is a `#[roopert(parent, ...)]` attribute
     pub fn is_parent(&self) -> bool {
-        match self {
-            Self::Parent(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Parent(_))
     }
     
+    /// Whether this is a `#[roopert(extends, ...)]` attribute
     pub fn is_extends(&self) -> bool {
-        match self {
-            Self::Extends(_) => true,
-            _ => false,
-        }
+        matches!(self, Self::Extends(_))
     }
     
+    /// Whether this is a `#[roopert(get, ...)]` attribute
     pub fn is_getter(&self) -> bool {
-        match self {
-            Self::Get(_) => true,
-            _ => false
-        }
+        matches!(self, Self::Get(_))
     }
     
+    /// Whether this is a `#[roopert(set, ...)]` attribute
     pub fn is_setter(&self) -> bool {
-        match self {
-            Self::Set(_) => true,
-            _ => false
-        }
+        matches!(self, Self::Set(_))
+    }
+    
+    /// Whether this is a `#[roopert(skip, ...)]` attribute
+    pub fn is_skip(&self) -> bool {
+        matches!(self, Self::Skip(_))
+    }
+    
+    /// Whether this is a `#[roopert(collection, ...)]` attribute
+    pub fn is_collection(&self) -> bool {
+        matches!(self, Self::Collection(_))
+    }
+    
+    /// Whether this is a `#[roopert(new, ...)]` attribute
+    pub fn is_new(&self) -> bool {
+        matches!(self, Self::New(_))
+    }
+    
+    /// Whether this is a `#[roopert(builder, ...)]` attribute
+    pub fn is_builder(&self) -> bool {
+        matches!(self, Self::Builder(_))
+    }
+    
+    /// Whether this is a `#[roopert(class, ...)]` attribute
+    pub fn is_class(&self) -> bool {
+        matches!(self, Self::Class(_))
+    }
+    
+    /// Whether this is a `#[roopert(overrides, ...)]` attribute
+    pub fn is_overrides(&self) -> bool {
+        matches!(self, Self::Overrides(_))
+    }
+    
+    /// Whether this is a `#[roopert(override, ...)]` attribute
+    pub fn is_override(&self) -> bool {
+        matches!(self, Self::Override(_))
+    }
+    
+    /// Whether this is a `#[roopert(abstract, ...)]` attribute
+    pub fn is_abstract(&self) -> bool {
+        matches!(self, Self::Abstract(_))
+    }
+    
+    /// Whether this is a `#[roopert(delegatable, ...)]` attribute
+    pub fn is_delegatable(&self) -> bool {
+        matches!(self, Self::Delegatable(_))
+    }
+    
+    /// Whether this is a `#[roopert(delegate, ...)]` attribute
+    pub fn is_delegate(&self) -> bool {
+        matches!(self, Self::Delegate(_))
+    }
+    
+    /// Whether this is a `#[roopert(delegated, ...)]` attribute
+    pub fn is_delegated(&self) -> bool {
+        matches!(self, Self::Delegated(_))
+    }
+    
+    /// Whether this is a `#[roopert(object_trait, ...)]` attribute
+    pub fn is_object_trait(&self) -> bool {
+        matches!(self, Self::ObjectTrait(_))
+    }
+    
+    /// Whether this is a `#[roopert(dispatch, ...)]` attribute
+    pub fn is_dispatch(&self) -> bool {
+        matches!(self, Self::Dispatch(_))
+    }
+    
+    /// Whether this is a `#[roopert(reflect, ...)]` attribute
+    pub fn is_reflect(&self) -> bool {
+        matches!(self, Self::Reflect(_))
+    }
+    
+    /// Whether this is a `#[roopert(properties, ...)]` attribute
+    pub fn is_properties(&self) -> bool {
+        matches!(self, Self::Properties(_))
+    }
+    
+    /// Whether this is a `#[roopert(crate = "...")]` attribute
+    pub fn is_crate(&self) -> bool {
+        matches!(self, Self::Crate(_))
     }
 }
 
 impl Generate for RoopertAttributeType {
-    fn generate(&mut self, input: TokenStream) -> core::result::Result<TokenStream, String> {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
         match self {
             Self::Parent(parent) => parent.generate(input),
             Self::Extends(extends) => extends.generate(input),
             Self::Accessors(accessors) => accessors.generate(input),
             Self::Get(getters) => getters.generate(input),
             Self::Set(setters) => setters.generate(input),
+            Self::Skip(skip) => skip.generate(input),
+            Self::Collection(collection) => collection.generate(input),
+            Self::New(new) => new.generate(input),
+            Self::Builder(builder) => builder.generate(input),
+            Self::Class(class) => class.generate(input),
+            Self::Overrides(overrides) => overrides.generate(input),
+            Self::Override(override_attr) => override_attr.generate(input),
+            Self::Abstract(abstract_attr) => abstract_attr.generate(input),
+            Self::Delegatable(delegatable) => delegatable.generate(input),
+            Self::Delegate(delegate) => delegate.generate(input),
+            Self::Delegated(delegated) => delegated.generate(input),
+            Self::ObjectTrait(object_trait) => object_trait.generate(input),
+            Self::Dispatch(dispatch) => dispatch.generate(input),
+            Self::Reflect(reflect) => reflect.generate(input),
+            Self::Properties(properties) => properties.generate(input),
+            Self::Crate(crate_attr) => crate_attr.generate(input),
         }
     }
     
@@ -61,51 +190,129 @@ impl Generate for RoopertAttributeType {
             Self::Accessors(accessors) => accessors.auto_append(),
             Self::Get(getters) => getters.auto_append(),
             Self::Set(setters) => setters.auto_append(),
+            Self::Skip(skip) => skip.auto_append(),
+            Self::Collection(collection) => collection.auto_append(),
+            Self::New(new) => new.auto_append(),
+            Self::Builder(builder) => builder.auto_append(),
+            Self::Class(class) => class.auto_append(),
+            Self::Overrides(overrides) => overrides.auto_append(),
+            Self::Override(override_attr) => override_attr.auto_append(),
+            Self::Abstract(abstract_attr) => abstract_attr.auto_append(),
+            Self::Delegatable(delegatable) => delegatable.auto_append(),
+            Self::Delegate(delegate) => delegate.auto_append(),
+            Self::Delegated(delegated) => delegated.auto_append(),
+            Self::ObjectTrait(object_trait) => object_trait.auto_append(),
+            Self::Dispatch(dispatch) => dispatch.auto_append(),
+            Self::Reflect(reflect) => reflect.auto_append(),
+            Self::Properties(properties) => properties.auto_append(),
+            Self::Crate(crate_attr) => crate_attr.auto_append(),
+        }
+    }
+    
+    fn set_crate_path(&mut self, crate_path: &CratePath) {
+        match self {
+            Self::Parent(parent) => parent.set_crate_path(crate_path),
+            Self::Extends(extends) => extends.set_crate_path(crate_path),
+            Self::Accessors(accessors) => accessors.set_crate_path(crate_path),
+            Self::Get(getters) => getters.set_crate_path(crate_path),
+            Self::Set(setters) => setters.set_crate_path(crate_path),
+            Self::Skip(skip) => skip.set_crate_path(crate_path),
+            Self::Collection(collection) => collection.set_crate_path(crate_path),
+            Self::New(new) => new.set_crate_path(crate_path),
+            Self::Builder(builder) => builder.set_crate_path(crate_path),
+            Self::Class(class) => class.set_crate_path(crate_path),
+            Self::Overrides(overrides) => overrides.set_crate_path(crate_path),
+            Self::Override(override_attr) => override_attr.set_crate_path(crate_path),
+            Self::Abstract(abstract_attr) => abstract_attr.set_crate_path(crate_path),
+            Self::Delegatable(delegatable) => delegatable.set_crate_path(crate_path),
+            Self::Delegate(delegate) => delegate.set_crate_path(crate_path),
+            Self::Delegated(delegated) => delegated.set_crate_path(crate_path),
+            Self::ObjectTrait(object_trait) => object_trait.set_crate_path(crate_path),
+            Self::Dispatch(dispatch) => dispatch.set_crate_path(crate_path),
+            Self::Reflect(reflect) => reflect.set_crate_path(crate_path),
+            Self::Properties(properties) => properties.set_crate_path(crate_path),
+            Self::Crate(crate_attr) => crate_attr.set_crate_path(crate_path),
         }
     }
 }
 
+/// A `#[roopert(...)]` attribute, as parsed from the parameters of the attribute
 #[cfg_attr(feature="verbose", derive(Debug))]
 pub struct RoopertAttribute {
     //ident: Ident,
+    /// Type of the attribute
     pub attr: RoopertAttributeType,
+    /// Explicit crate path, from a `crate = "..."` parameter before the attribute type
+    pub crate_path: Option<CratePath>,
 }
 
 impl Parse for RoopertAttribute {
     fn parse(input: ParseStream) -> Result<Self> {
-        let ident: Ident = input.parse()?;
+        let ident: Ident = Ident::parse_any(input)?; // some attribute types are keywords, like override
         // ignore comma -- just a separator
         if input.peek(Token![,]) {
             let _: Token![,] = input.parse()?;
         }
-        match &ident.to_string() as &str {
-            "parent" => Ok(Self {
-                    //ident: ident,
-                    attr: RoopertAttributeType::Parent(ParentAttribute::parse(input)?),
-                }),
-            "extend" | "extends" => Ok(Self {
-                    //ident: ident,
-                    attr: RoopertAttributeType::Extends(ExtendsAttribute::parse(input)?),
-                }),
-            "accessors" => Ok(Self {
-                //ident: ident,
-                attr: RoopertAttributeType::Accessors(AccessorsAttribute::parse(input)?),
-            }),
-            "get" => Ok(Self {
-                //ident: ident,
-                attr: RoopertAttributeType::Get(GetterAttribute::parse(input)?),
-            }),
-            "set" => Ok(Self {
-                //ident: ident,
-                attr: RoopertAttributeType::Set(SetterAttribute::parse(input)?),
-            }),
-            _ => Err(input.error(format!("unexpected identifier {}", ident.to_string())))
-        }
+        let attr = match &ident.to_string() as &str {
+            "parent" => RoopertAttributeType::Parent(ParentAttribute::parse(input)?),
+            "extend" | "extends" => RoopertAttributeType::Extends(ExtendsAttribute::parse(input)?),
+            "accessors" => RoopertAttributeType::Accessors(AccessorsAttribute::parse(input)?),
+            "get" => RoopertAttributeType::Get(GetterAttribute::parse(input)?),
+            "set" => RoopertAttributeType::Set(SetterAttribute::parse(input)?),
+            "skip" => RoopertAttributeType::Skip(SkipAttribute::parse(input)?),
+            "collection" => RoopertAttributeType::Collection(CollectionAttribute::parse(input)?),
+            "new" => RoopertAttributeType::New(NewAttribute::parse(input)?),
+            "builder" => RoopertAttributeType::Builder(BuilderAttribute::parse(input)?),
+            "class" => RoopertAttributeType::Class(ClassAttribute::parse(input)?),
+            "overrides" => RoopertAttributeType::Overrides(OverridesAttribute::parse(input)?),
+            "override" => RoopertAttributeType::Override(OverrideAttribute::parse(input)?),
+            "abstract" => RoopertAttributeType::Abstract(AbstractAttribute::parse(input)?),
+            "delegatable" => RoopertAttributeType::Delegatable(DelegatableAttribute::parse(input)?),
+            "delegate" => RoopertAttributeType::Delegate(DelegateAttribute::parse(input)?),
+            "delegated" => RoopertAttributeType::Delegated(DelegatedAttribute::parse(input)?),
+            "object_trait" => RoopertAttributeType::ObjectTrait(ObjectTraitAttribute::parse(input)?),
+            "dispatch" => RoopertAttributeType::Dispatch(DispatchAttribute::parse(input)?),
+            "reflect" => RoopertAttributeType::Reflect(ReflectAttribute::parse(input)?),
+            "properties" => RoopertAttributeType::Properties(PropertiesAttribute::parse(input)?),
+            "crate" => {
+                let crate_attr = CrateAttribute::parse(input)?;
+                if input.is_empty() {
+                    RoopertAttributeType::Crate(crate_attr)
+                } else {
+                    // #[roopert(crate = "...", type, ...)] uses the crate path for a single attribute
+                    let _: Token![,] = input.parse()?;
+                    let mut inner = RoopertAttribute::parse(input)?;
+                    inner.crate_path = Some(crate_attr.crate_path());
+                    return Ok(inner);
+                }
+            },
+            _ => return Err(Error::new(ident.span(), format!("unexpected identifier {}", ident)))
+        };
+        Ok(Self {
+            attr,
+            crate_path: None,
+        })
+    }
+}
+
+impl RoopertAttribute {
+    /// Roopert attributes of an item which have not been expanded yet, like the attributes below the one being expanded
+    pub fn declared(attrs: &[Attribute]) -> Result<Vec<(&Attribute, RoopertAttribute)>> {
+        attrs.iter()
+            .filter(|attr| is_roopert_attribute(attr))
+            .map(|attr| attr.parse_args::<RoopertAttribute>()
+                .map(|parsed_attr| (attr, parsed_attr))
+                .map_err(|e| Error::new(e.span(), format!("Malformed #[roopert(...)] attribute: {}", e))))
+            .collect()
     }
 }
 
 impl Generate for RoopertAttribute {
-    fn generate(&mut self, input: TokenStream) -> core::result::Result<TokenStream, String> {
+    fn generate(&mut self, input: TokenStream) -> Result<TokenStream> {
+        let crate_path = self.crate_path.clone()
+            .or_else(|| CrateAttribute::declared_crate_path(&input))
+            .unwrap_or_default();
+        self.attr.set_crate_path(&crate_path);
         self.attr.generate(input)
     }
     
@@ -1,4 +1,4 @@
-use syn::{Result, Ident, Type, Expr, Token, punctuated::Punctuated, Path, Lit};
+use syn::{Result, Ident, Type, Expr, Token, punctuated::Punctuated, Path, Lit, Error};
 use syn::parse::{Parse, ParseStream};
 
 use quote::{quote, format_ident, ToTokens};
@@ -8,27 +8,152 @@ use proc_macro2::{TokenStream};
 use super::Generate;
 use super::parse::single_path_segment;
 
+/// Setter of a field, from a `#[set(...)]` or `#[roopert(set, ...)]` field attribute
 #[cfg_attr(feature="verbose", derive(Debug))]
 pub struct SetterAttribute {
     // TODO
     pre: Option<Expr>,
     post: Option<Expr>,
     name: Option<String>,
+    skip: bool,
+    replace: bool,
+    take: bool,
+    update: bool,
 }
 
 impl SetterAttribute {
+    /// Setter named `set_{field}`, without the replace, take and update methods
     pub fn with_accessor_defaults() -> Self {
         Self {
             pre: None,
             post: None,
             name: None,
+            skip: false,
+            replace: false,
+            take: false,
+            update: false,
         }
     }
     
+    /// Sets the name of the setter
+    pub fn with_name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+    
+    /// Evaluates the expression at the start of the setter methods
+    pub fn with_pre(mut self, pre: Expr) -> Self {
+        self.pre = Some(pre);
+        self
+    }
+    
+    /// Evaluates the expression at the end of the setter methods
+    pub fn with_post(mut self, post: Expr) -> Self {
+        self.post = Some(post);
+        self
+    }
+    
+    /// Also generates a `replace_{field}` method
+    pub fn with_replace(mut self, replace: bool) -> Self {
+        self.replace = replace;
+        self
+    }
+    
+    /// Also generates a `take_{field}` method
+    pub fn with_take(mut self, take: bool) -> Self {
+        self.take = take;
+        self
+    }
+    
+    /// Also generates an `update_{field}` method
+    pub fn with_update(mut self, update: bool) -> Self {
+        self.update = update;
+        self
+    }
+    
+    /// Whether the field does not have a setter
+    pub fn is_skip(&self) -> bool {
+        self.skip
+    }
+    
+    /// Name of the setter of the field
+    pub fn setter_ident(&self, target_field: &Ident) -> Ident {
+        self.name.as_ref().map(|name| format_ident!("{}", name))
+            .unwrap_or_else(|| format_ident!("set_{}", target_field))
+    }
+    
+    /// Setter method of the field, to put in an inherent impl block of the struct
     pub fn impl_set_fn(&self, target_field: &Ident, parent_type: &Type) -> TokenStream {
-        let setter_fn_name = self.name.as_ref()
-            .and_then(|name| Some(format_ident!("{}", name)))
-            .unwrap_or_else(|| format_ident!("set_{}", target_field));
+        let setter_fn_name = self.setter_ident(target_field);
+        let (pre_op, post_op) = self.hook_ops();
+        quote!{
+            pub fn #setter_fn_name(&mut self, x: #parent_type) {
+                #pre_op
+                self.#target_field = x;
+                #post_op
+            }
+        }
+    }
+    
+    /// `replace_{field}` method, which returns the previous value of the field
+    pub fn impl_replace_fn(&self, target_field: &Ident, parent_type: &Type) -> TokenStream {
+        let replace_fn_name = format_ident!("replace_{}", target_field);
+        let (pre_op, post_op) = self.hook_ops();
+        quote!{
+            pub fn #replace_fn_name(&mut self, x: #parent_type) -> #parent_type {
+                #pre_op
+                let replaced = core::mem::replace(&mut self.#target_field, x);
+                #post_op
+                replaced
+            }
+        }
+    }
+    
+    /// `take_{field}` method, which replaces the field with its default value
+    pub fn impl_take_fn(&self, target_field: &Ident, parent_type: &Type) -> TokenStream {
+        let take_fn_name = format_ident!("take_{}", target_field);
+        let (pre_op, post_op) = self.hook_ops();
+        quote!{
+            pub fn #take_fn_name(&mut self) -> #parent_type where #parent_type: core::default::Default {
+                #pre_op
+                let taken = core::mem::take(&mut self.#target_field);
+                #post_op
+                taken
+            }
+        }
+    }
+    
+    /// `update_{field}` method, which modifies the field in place with a closure
+    pub fn impl_update_fn(&self, target_field: &Ident, parent_type: &Type) -> TokenStream {
+        let update_fn_name = format_ident!("update_{}", target_field);
+        let (pre_op, post_op) = self.hook_ops();
+        quote!{
+            pub fn #update_fn_name(&mut self, f: impl core::ops::FnOnce(&mut #parent_type)) {
+                #pre_op
+                f(&mut self.#target_field);
+                #post_op
+            }
+        }
+    }
+    
+    /// Setter method and the enabled replace, take and update methods of the field
+    pub fn impl_set_fns(&self, target_field: &Ident, parent_type: &Type) -> TokenStream {
+        let mut tokens = vec![self.impl_set_fn(target_field, parent_type)];
+        if self.replace {
+            tokens.push(self.impl_replace_fn(target_field, parent_type));
+        }
+        if self.take {
+            tokens.push(self.impl_take_fn(target_field, parent_type));
+        }
+        if self.update {
+            tokens.push(self.impl_update_fn(target_field, parent_type));
+        }
+        quote!{
+            #(#tokens)*
+        }
+    }
+    
+    fn hook_ops(&self) -> (TokenStream, TokenStream) {
         let pre_op = match self.pre.as_ref() {
             Some(op) => quote!{#op;}.to_token_stream(),
             None => quote!{}.to_token_stream()
@@ -37,25 +162,19 @@ impl SetterAttribute {
             Some(op) => quote!{#op;}.to_token_stream(),
             None => quote!{}.to_token_stream()
         };
-        quote!{
-            pub fn #setter_fn_name(&mut self, x: #parent_type) {
-                #pre_op
-                self.#target_field = x;
-                #post_op
-            }
-        }
+        (pre_op, post_op)
     }
     
     #[inline]
-    fn name_to_string(rhs: &Expr, input: ParseStream) -> Result<String> {
+    fn name_to_string(rhs: &Expr) -> Result<String> {
         match rhs {
             Expr::Lit(lit) => {
                 match &lit.lit {
                     Lit::Str(lit_str) => Ok(lit_str.value()),
-                    _ => Err(input.error(format!("Invalid literal in right hand side of name parameter #[roopert(set, name = {})]", lit.to_token_stream())))
+                    _ => Err(Error::new_spanned(lit, format!("Invalid literal in right hand side of name parameter #[roopert(set, name = {})]", lit.to_token_stream())))
                 }
             },
-            _ => Err(input.error(format!("Unrecognised right hand side of name parameter #[roopert(set, name = {})]", rhs.to_token_stream())))
+            _ => Err(Error::new_spanned(rhs, format!("Unrecognised right hand side of name parameter #[roopert(set, name = {})]", rhs.to_token_stream())))
         }
     }
 }
@@ -65,45 +184,75 @@ impl Parse for SetterAttribute {
         let mut pre_effect = None;
         let mut post_effect = None;
         let mut name = None;
-        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| input.error(format!("Invalid parameter in #[roopert(set, ...)]: {}", e)))?;
+        let mut skip = false;
+        let mut replace = false;
+        let mut take = false;
+        let mut update = false;
+        let params = Punctuated::<Expr, Token![,]>::parse_terminated(input).map_err(|e| Error::new(e.span(), format!("Invalid parameter in #[roopert(set, ...)]: {}", e)))?;
         for param in params.iter() {
             match param {
                 Expr::Assign(assign) => {
                     if let Expr::Path(var) = &*assign.left {
-                        let ident = single_path_segment(&var.path, input, setter_lhs_err)?;
+                        let ident = single_path_segment(&var.path, setter_lhs_err)?;
                         match &ident.to_string().to_lowercase() as &str {
                             "pre" => {
-                                pre_effect = Some((&*assign.right).clone());
+                                pre_effect = Some((*assign.right).clone());
                                 Ok(())
                             },
                             "post" => {
-                                post_effect = Some((&*assign.right).clone());
+                                post_effect = Some((*assign.right).clone());
                                 Ok(())
                             },
                             "name" => {
-                                name = Some(Self::name_to_string(&*assign.right, input)?);
+                                name = Some(Self::name_to_string(&assign.right)?);
                                 Ok(())
                             }
-                            _ => Err(input.error(format!("Unrecognised left hand side of assignment {} in #[roopert(set, ...)]", ident.to_string())))
+                            _ => Err(Error::new_spanned(&ident, format!("Unrecognised left hand side of assignment {} in #[roopert(set, ...)]", ident)))
                         }
                     } else {
-                        Err(input.error(format!("Unsupported left hand side of assignment {} in #[roopert(set, ...)]", assign.to_token_stream())))
+                        Err(Error::new_spanned(assign, format!("Unsupported left hand side of assignment {} in #[roopert(set, ...)]", assign.to_token_stream())))
+                    }
+                },
+                Expr::Path(var) => {
+                    let ident = single_path_segment(&var.path, setter_lhs_err)?;
+                    match &ident.to_string().to_lowercase() as &str {
+                        "skip" => {
+                            skip = true;
+                            Ok(())
+                        },
+                        "replace" => {
+                            replace = true;
+                            Ok(())
+                        },
+                        "take" => {
+                            take = true;
+                            Ok(())
+                        },
+                        "update" => {
+                            update = true;
+                            Ok(())
+                        },
+                        _ => Err(Error::new_spanned(&ident, format!("Unrecognised attribute parameter {} in #[roopert(set, ...)]", ident)))
                     }
                 },
-                _ => Err(input.error(format!("Unrecognised attribute parameter {} in #[roopert(set, ...)]", param.to_token_stream())))
+                _ => Err(Error::new_spanned(param, format!("Unrecognised attribute parameter {} in #[roopert(set, ...)]", param.to_token_stream())))
             }?;
         }
         Ok(Self {
             pre: pre_effect,
             post: post_effect,
-            name: name,
+            name,
+            skip,
+            replace,
+            take,
+            update,
         })
     }
 }
 
 impl Generate for SetterAttribute {
-    fn generate(&mut self, _input: TokenStream) -> core::result::Result<TokenStream, String> {
-        Ok(quote!{}.into())
+    fn generate(&mut self, _input: TokenStream) -> Result<TokenStream> {
+        Ok(quote!{})
     }
     
     fn auto_append(&self) -> bool {true}
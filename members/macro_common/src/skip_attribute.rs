@@ -0,0 +1,28 @@
+use syn::{Result};
+use syn::parse::{Parse, ParseStream};
+
+use quote::quote;
+
+use proc_macro2::{TokenStream};
+
+use super::{Generate};
+
+/// Field without accessors, from a `#[roopert(skip)]` field attribute
+#[cfg_attr(feature="verbose", derive(Debug))]
+#[derive(Default)]
+pub struct SkipAttribute {
+}
+
+impl Parse for SkipAttribute {
+    fn parse(_input: ParseStream) -> Result<Self> {
+        Ok(Self{})
+    }
+}
+
+impl Generate for SkipAttribute {
+    fn generate(&mut self, _input: TokenStream) -> Result<TokenStream> {
+        Ok(quote!{})
+    }
+    
+    fn auto_append(&self) -> bool {true}
+}
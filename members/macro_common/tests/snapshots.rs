@@ -0,0 +1,293 @@
+//! Snapshot tests of generated code
+//!
+//! Every test runs one roopert attribute on an item and compares the pretty-printed output
+//! against `tests/snapshots/<name>.expanded.rs`.
+//! After an intended change to generated code, run with `ROOPERT_BLESS=1` to rewrite the snapshots and review the diff.
+
+use std::path::PathBuf;
+
+use proc_macro2::TokenStream;
+
+use quote::quote;
+
+use syn::parse_quote;
+
+use roopert_macro_common::{Generate, RoopertAttribute};
+
+fn expand(mut attribute: RoopertAttribute, item: TokenStream) -> String {
+    let generated = attribute.generate_auto(item).unwrap();
+    let file: syn::File = syn::parse2(generated).unwrap();
+    prettyplease::unparse(&file)
+}
+
+fn assert_snapshot(name: &str, attribute: RoopertAttribute, item: TokenStream) {
+    let actual = expand(attribute, item);
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{}.expanded.rs", name));
+    if std::env::var_os("ROOPERT_BLESS").is_some() {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, &actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("Missing snapshot {} (run with ROOPERT_BLESS=1 to create it)", path.display()));
+    if expected != actual {
+        panic!("Generated code does not match snapshot {} (run with ROOPERT_BLESS=1 to update it)\n--- expected ---\n{}\n--- actual ---\n{}", path.display(), expected, actual);
+    }
+}
+
+#[test]
+fn extends_parent_snapshot() {
+    assert_snapshot("extends_parent", parse_quote!{extends, String}, quote!{
+        struct Wrapper<T: 'static> {
+            #[roopert(parent)]
+            inner: T,
+            name: String,
+        }
+    });
+}
+
+#[test]
+fn extends_snapshot() {
+    assert_snapshot("extends", parse_quote!{extends, String}, quote!{
+        struct Name {
+            value: String,
+            #[roopert(get)]
+            len: usize,
+        }
+    });
+}
+
+#[test]
+fn extends_base_snapshot() {
+    assert_snapshot("extends_base", parse_quote!{extends}, quote!{
+        struct Base {
+            id: usize,
+        }
+    });
+}
+
+#[test]
+fn extends_forward_snapshot() {
+    assert_snapshot("extends_forward", parse_quote!{extends, Vec<T>, forward(IntoIterator, Index, Debug)}, quote!{
+        struct Stack<T> {
+            items: Vec<T>,
+        }
+    });
+}
+
+#[test]
+fn extends_ops_snapshot() {
+    assert_snapshot("extends_ops", parse_quote!{extends, u32, ops(Add, AddAssign, Not)}, quote!{
+        struct Meters {
+            value: u32,
+        }
+    });
+}
+
+#[test]
+fn accessors_snapshot() {
+    assert_snapshot("accessors", parse_quote!{accessors, get = All, set = Except(id)}, quote!{
+        pub struct Item {
+            id: usize,
+            #[roopert(get, pre = self.touch(), mutable = true, name = "label")]
+            name: String,
+            #[roopert(set, replace, take, update, post = self.changed())]
+            count: u32,
+            #[roopert(skip)]
+            cache: Option<u32>,
+        }
+    });
+}
+
+#[test]
+fn accessors_collection_snapshot() {
+    assert_snapshot("accessors_collection", parse_quote!{accessors, collection = All}, quote!{
+        struct Inventory {
+            items: Vec<String>,
+            #[roopert(collection, singular = "tag", post = self.changed())]
+            tags: std::collections::HashSet<String>,
+        }
+    });
+}
+
+#[test]
+fn new_snapshot() {
+    assert_snapshot("new", parse_quote!{new, name = "create", vis = "pub(crate)", into}, quote!{
+        struct Counter {
+            name: String,
+            #[roopert(new, default)]
+            count: usize,
+        }
+    });
+}
+
+#[test]
+fn builder_snapshot() {
+    assert_snapshot("builder", parse_quote!{builder}, quote!{
+        pub struct Request {
+            url: String,
+            #[roopert(builder, default = 30)]
+            timeout: u32,
+        }
+    });
+}
+
+#[test]
+fn builder_typestate_snapshot() {
+    assert_snapshot("builder_typestate", parse_quote!{builder, typestate}, quote!{
+        struct Point {
+            x: i32,
+            #[roopert(builder, default)]
+            y: i32,
+        }
+    });
+}
+
+#[test]
+fn class_snapshot() {
+    assert_snapshot("class", parse_quote!{class}, quote!{
+        impl Animal {
+            pub fn new(name: &str) -> Self {
+                Self { name: name.to_string() }
+            }
+
+            pub fn speak(&self) -> String {
+                format!("{} makes a sound", self.name)
+            }
+        }
+    });
+}
+
+#[test]
+fn overrides_snapshot() {
+    assert_snapshot("overrides", parse_quote!{overrides, Animal}, quote!{
+        impl Dog {
+            #[roopert(override)]
+            pub fn speak(&self) -> String {
+                format!("{} (woof)", self.super_speak())
+            }
+        }
+    });
+}
+
+#[test]
+fn abstract_snapshot() {
+    assert_snapshot("abstract", parse_quote!{abstract, Shape}, quote!{
+        trait ShapeMethods {
+            fn area(&self) -> f64;
+
+            fn describe(&self) -> String {
+                format!("{} with area {}", self.as_ref().name, self.area())
+            }
+        }
+    });
+}
+
+#[test]
+fn delegatable_snapshot() {
+    assert_snapshot("delegatable", parse_quote!{delegatable}, quote!{
+        pub trait Counter {
+            type Count;
+
+            fn count(&self) -> Self::Count;
+
+            fn increment(&mut self, by: usize);
+        }
+    });
+}
+
+#[test]
+fn delegate_snapshot() {
+    assert_snapshot("delegate", parse_quote!{delegate(Counter => counter, std::fmt::Display => label)}, quote!{
+        struct Delegator {
+            counter: SimpleCounter,
+            label: String,
+        }
+    });
+}
+
+#[test]
+fn object_trait_snapshot() {
+    assert_snapshot("object_trait", parse_quote!{object_trait, base = WidgetBase}, quote!{
+        trait Widget {
+            fn draw(&self) -> String;
+        }
+    });
+}
+
+#[test]
+fn dispatch_snapshot() {
+    assert_snapshot("dispatch", parse_quote!{dispatch(Shape, std::fmt::Display)}, quote!{
+        enum AnyShape {
+            Circle(Circle),
+            Square(Square),
+        }
+    });
+}
+
+#[test]
+fn reflect_snapshot() {
+    assert_snapshot("reflect", parse_quote!{reflect, parents = nested}, quote!{
+        #[roopert(accessors, get = All)]
+        struct Panel {
+            node: Node,
+            title: String,
+        }
+    });
+}
+
+#[test]
+fn properties_snapshot() {
+    assert_snapshot("properties", parse_quote!{properties}, quote!{
+        #[roopert(accessors, get = All, set = Except(id))]
+        struct Setting {
+            id: usize,
+            value: f64,
+        }
+    });
+}
+
+#[test]
+fn crate_snapshot() {
+    assert_snapshot("crate", parse_quote!{crate = "my_crate::roopert"}, quote!{
+        #[roopert(properties)]
+        struct Config {
+            verbose: bool,
+        }
+    });
+}
+
+#[test]
+fn crate_path_snapshot() {
+    assert_snapshot("crate_path", parse_quote!{crate = "my_crate::roopert", properties}, quote!{
+        #[roopert(accessors, get = All)]
+        struct Config {
+            verbose: bool,
+        }
+    });
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn accessors_serde_snapshot() {
+    assert_snapshot("accessors_serde", parse_quote!{accessors, set = All, serde}, quote!{
+        struct Square {
+            #[roopert(set, pre = assert!(x > 0))]
+            side: u32,
+        }
+    });
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn extends_serde_snapshot() {
+    assert_snapshot("extends_serde", parse_quote!{extends, Shape, serde}, quote!{
+        struct Square {
+            shape: Shape,
+            side: u32,
+        }
+    });
+}
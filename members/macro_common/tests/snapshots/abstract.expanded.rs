@@ -0,0 +1,14 @@
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` extends abstract `Shape` but does not implement its abstract methods",
+    label = "missing abstract methods of `Shape`"
+)]
+trait ShapeMethods: core::convert::AsRef<Shape> {
+    fn area(&self) -> f64;
+    fn describe(&self) -> String {
+        format!("{} with area {}", self.as_ref().name, self.area())
+    }
+}
+impl ::roopert::__private::IsAbstract for Shape {}
+impl<
+    __RoopertChild: ShapeMethods + ?Sized,
+> ::roopert::__private::AbstractBase<__RoopertChild> for Shape {}
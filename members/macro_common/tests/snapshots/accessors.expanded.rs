@@ -0,0 +1,42 @@
+pub struct Item {
+    id: usize,
+    name: String,
+    count: u32,
+    cache: Option<u32>,
+}
+impl Item {
+    pub fn get_id(&self) -> &'_ usize {
+        &self.id
+    }
+    pub fn label(&mut self) -> &'_ mut String {
+        self.touch();
+        &mut self.name
+    }
+    pub fn get_count(&self) -> &'_ u32 {
+        &self.count
+    }
+    pub fn set_name(&mut self, x: String) {
+        self.name = x;
+    }
+    pub fn set_count(&mut self, x: u32) {
+        self.count = x;
+        self.changed();
+    }
+    pub fn replace_count(&mut self, x: u32) -> u32 {
+        let replaced = core::mem::replace(&mut self.count, x);
+        self.changed();
+        replaced
+    }
+    pub fn take_count(&mut self) -> u32
+    where
+        u32: core::default::Default,
+    {
+        let taken = core::mem::take(&mut self.count);
+        self.changed();
+        taken
+    }
+    pub fn update_count(&mut self, f: impl core::ops::FnOnce(&mut u32)) {
+        f(&mut self.count);
+        self.changed();
+    }
+}
@@ -0,0 +1,45 @@
+struct Inventory {
+    items: Vec<String>,
+    tags: std::collections::HashSet<String>,
+}
+impl Inventory {
+    pub fn items(&self) -> &'_ [String] {
+        &self.items
+    }
+    pub fn items_iter(&self) -> impl core::iter::Iterator<Item = &'_ String> + '_ {
+        self.items.iter()
+    }
+    pub fn push_item(&mut self, x: String) {
+        self.items.push(x);
+    }
+    pub fn remove_item(&mut self, index: usize) -> String {
+        let removed = self.items.remove(index);
+        removed
+    }
+    pub fn clear_items(&mut self) {
+        self.items.clear();
+    }
+    pub fn tags(&self) -> &'_ std::collections::HashSet<String> {
+        &self.tags
+    }
+    pub fn tags_iter(&self) -> impl core::iter::Iterator<Item = &'_ String> + '_ {
+        self.tags.iter()
+    }
+    pub fn insert_tag(&mut self, x: String) -> bool {
+        let inserted = self.tags.insert(x);
+        self.changed();
+        inserted
+    }
+    pub fn contains_tag(&self, x: &String) -> bool {
+        self.tags.contains(x)
+    }
+    pub fn remove_tag(&mut self, x: &String) -> bool {
+        let removed = self.tags.remove(x);
+        self.changed();
+        removed
+    }
+    pub fn clear_tags(&mut self) {
+        self.tags.clear();
+        self.changed();
+    }
+}
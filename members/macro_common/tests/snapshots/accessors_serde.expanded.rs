@@ -0,0 +1,34 @@
+#[serde(try_from = "__RoopertDeserializeSquare")]
+struct Square {
+    side: u32,
+}
+impl Square {
+    pub fn set_side(&mut self, x: u32) {
+        assert!(x > 0);
+        self.side = x;
+    }
+}
+#[doc(hidden)]
+#[derive(::roopert::__private::serde::Deserialize)]
+#[serde(crate = ":: roopert :: __private :: serde")]
+struct __RoopertDeserializeSquare {
+    side: u32,
+}
+impl core::convert::TryFrom<__RoopertDeserializeSquare> for Square
+where
+    u32: core::default::Default,
+{
+    type Error = ::roopert::__private::SetterHookError;
+    fn try_from(
+        shadow: __RoopertDeserializeSquare,
+    ) -> core::result::Result<Self, Self::Error> {
+        let __RoopertDeserializeSquare { side } = shadow;
+        ::roopert::__private::run_setter_hooks(move || {
+            let mut target = Self {
+                side: core::default::Default::default(),
+            };
+            target.set_side(side);
+            target
+        })
+    }
+}
@@ -0,0 +1,48 @@
+pub struct Request {
+    url: String,
+    timeout: u32,
+}
+pub struct RequestBuilder {
+    url: core::option::Option<String>,
+    timeout: core::option::Option<u32>,
+}
+impl RequestBuilder {
+    pub fn new() -> Self {
+        Self {
+            url: core::option::Option::None,
+            timeout: core::option::Option::None,
+        }
+    }
+    pub fn url(mut self, x: String) -> Self {
+        self.url = core::option::Option::Some(x);
+        self
+    }
+    pub fn timeout(mut self, x: u32) -> Self {
+        self.timeout = core::option::Option::Some(x);
+        self
+    }
+    pub fn build(self) -> core::result::Result<Request, ::roopert::BuildError> {
+        core::result::Result::Ok(Request {
+            url: match self.url {
+                core::option::Option::Some(x) => x,
+                core::option::Option::None => {
+                    return core::result::Result::Err(::roopert::BuildError::MissingField {
+                        target: "Request",
+                        field: "url",
+                    });
+                }
+            },
+            timeout: self.timeout.unwrap_or_else(|| 30),
+        })
+    }
+}
+impl core::default::Default for RequestBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Request {
+    pub fn builder() -> RequestBuilder {
+        RequestBuilder::new()
+    }
+}
@@ -0,0 +1,56 @@
+struct Point {
+    x: i32,
+    y: i32,
+}
+#[allow(dead_code)]
+struct PointBuilderXSet;
+#[allow(dead_code)]
+struct PointBuilderXMissing;
+struct PointBuilder<__X> {
+    x: core::option::Option<i32>,
+    y: core::option::Option<i32>,
+    __state: core::marker::PhantomData<fn() -> (__X,)>,
+}
+impl PointBuilder<PointBuilderXMissing> {
+    pub fn new() -> Self {
+        Self {
+            x: core::option::Option::None,
+            y: core::option::Option::None,
+            __state: core::marker::PhantomData,
+        }
+    }
+}
+impl core::default::Default for PointBuilder<PointBuilderXMissing> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<__X> PointBuilder<__X> {
+    pub fn x(self, x: i32) -> PointBuilder<PointBuilderXSet> {
+        PointBuilder {
+            x: core::option::Option::Some(x),
+            y: self.y,
+            __state: core::marker::PhantomData,
+        }
+    }
+    pub fn y(mut self, x: i32) -> Self {
+        self.y = core::option::Option::Some(x);
+        self
+    }
+}
+impl PointBuilder<PointBuilderXSet> {
+    pub fn build(self) -> Point {
+        Point {
+            x: match self.x {
+                core::option::Option::Some(x) => x,
+                core::option::Option::None => unreachable!("required field state is set"),
+            },
+            y: self.y.unwrap_or_default(),
+        }
+    }
+}
+impl Point {
+    pub fn builder() -> PointBuilder<PointBuilderXMissing> {
+        PointBuilder::new()
+    }
+}
@@ -0,0 +1,23 @@
+impl Animal {
+    pub fn new(name: &str) -> Self {
+        Self { name: name.to_string() }
+    }
+    pub fn speak(&self) -> String {
+        format!("{} makes a sound", self.name)
+    }
+}
+pub trait AnimalClass {
+    fn as_animal(&self) -> &Animal;
+    fn as_animal_mut(&mut self) -> &mut Animal;
+    fn speak(&self) -> String {
+        <Animal>::speak(<Self as AnimalClass>::as_animal(self))
+    }
+}
+impl AnimalClass for Animal {
+    fn as_animal(&self) -> &Animal {
+        self
+    }
+    fn as_animal_mut(&mut self) -> &mut Animal {
+        self
+    }
+}
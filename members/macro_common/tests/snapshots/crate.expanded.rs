@@ -0,0 +1,4 @@
+#[roopert(crate = "my_crate::roopert", properties)]
+struct Config {
+    verbose: bool,
+}
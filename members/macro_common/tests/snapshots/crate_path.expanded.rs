@@ -0,0 +1,63 @@
+#[roopert(accessors, get = All)]
+struct Config {
+    verbose: bool,
+}
+impl my_crate::roopert::Properties for Config {
+    fn properties(&self) -> &'static [&'static str] {
+        &["verbose"]
+    }
+    fn get_property(
+        &self,
+        name: &str,
+    ) -> core::result::Result<
+        my_crate::roopert::__private::alloc::string::String,
+        my_crate::roopert::PropertyError,
+    > {
+        #[allow(unused_imports)]
+        use my_crate::roopert::__private::{
+            DisplayProperty as _, DisplayUnsupported as _,
+        };
+        match name {
+            "verbose" => {
+                (&my_crate::roopert::__private::PropertyValue::<bool>::new())
+                    .display_property(&self.verbose)
+                    .ok_or(my_crate::roopert::PropertyError::Unsupported {
+                        target: "Config",
+                        property: "verbose",
+                    })
+            }
+            _ => {
+                core::result::Result::Err(my_crate::roopert::PropertyError::UnknownProperty {
+                    target: "Config",
+                    property: my_crate::roopert::__private::alloc::string::ToString::to_string(
+                        name,
+                    ),
+                })
+            }
+        }
+    }
+    fn set_property(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> core::result::Result<(), my_crate::roopert::PropertyError> {
+        #[allow(unused_imports)]
+        use my_crate::roopert::__private::{ParseProperty as _, ParseUnsupported as _};
+        match name {
+            "verbose" => {
+                core::result::Result::Err(my_crate::roopert::PropertyError::ReadOnly {
+                    target: "Config",
+                    property: "verbose",
+                })
+            }
+            _ => {
+                core::result::Result::Err(my_crate::roopert::PropertyError::UnknownProperty {
+                    target: "Config",
+                    property: my_crate::roopert::__private::alloc::string::ToString::to_string(
+                        name,
+                    ),
+                })
+            }
+        }
+    }
+}
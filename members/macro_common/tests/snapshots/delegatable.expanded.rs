@@ -0,0 +1,16 @@
+pub trait Counter {
+    type Count;
+    fn count(&self) -> Self::Count;
+    fn increment(&mut self, by: usize);
+}
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __roopert_delegate_Counter_8c90375d6bef796b {
+    ($($params:tt)*) => {
+        #[::roopert::roopert(delegated, $($params)*)] pub trait Counter { type Count; fn
+        count(& self) -> Self::Count; fn increment(& mut self, by : usize); }
+    };
+}
+#[doc(hidden)]
+#[allow(unused_imports)]
+pub use __roopert_delegate_Counter_8c90375d6bef796b as Counter;
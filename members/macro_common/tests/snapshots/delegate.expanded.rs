@@ -0,0 +1,15 @@
+struct Delegator {
+    counter: SimpleCounter,
+    label: String,
+}
+Counter! {
+    Counter, counter : SimpleCounter, impl for Delegator
+}
+impl std::fmt::Display for Delegator
+where
+    String: std::fmt::Display,
+{
+    fn fmt(&self, arg0: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <String as std::fmt::Display>::fmt(&self.label, arg0)
+    }
+}
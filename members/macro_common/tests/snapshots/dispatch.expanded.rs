@@ -0,0 +1,86 @@
+enum AnyShape {
+    Circle(Circle),
+    Square(Square),
+}
+Shape! {
+    Shape, enum { Circle(Circle), Square(Square), }, impl for AnyShape
+}
+impl std::fmt::Display for AnyShape
+where
+    Circle: std::fmt::Display,
+    Square: std::fmt::Display,
+{
+    fn fmt(&self, arg0: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Circle(inner) => <Circle as std::fmt::Display>::fmt(inner, arg0),
+            Self::Square(inner) => <Square as std::fmt::Display>::fmt(inner, arg0),
+        }
+    }
+}
+impl core::convert::From<Circle> for AnyShape {
+    fn from(value: Circle) -> Self {
+        Self::Circle(value)
+    }
+}
+impl core::convert::TryFrom<AnyShape> for Circle {
+    type Error = AnyShape;
+    #[allow(unreachable_patterns)]
+    fn try_from(value: AnyShape) -> core::result::Result<Self, Self::Error> {
+        match value {
+            AnyShape::Circle(inner) => core::result::Result::Ok(inner),
+            other => core::result::Result::Err(other),
+        }
+    }
+}
+impl core::convert::From<Square> for AnyShape {
+    fn from(value: Square) -> Self {
+        Self::Square(value)
+    }
+}
+impl core::convert::TryFrom<AnyShape> for Square {
+    type Error = AnyShape;
+    #[allow(unreachable_patterns)]
+    fn try_from(value: AnyShape) -> core::result::Result<Self, Self::Error> {
+        match value {
+            AnyShape::Square(inner) => core::result::Result::Ok(inner),
+            other => core::result::Result::Err(other),
+        }
+    }
+}
+#[allow(dead_code)]
+impl AnyShape {
+    #[allow(unreachable_patterns)]
+    fn as_circle(&self) -> core::option::Option<&Circle> {
+        match self {
+            Self::Circle(inner) => core::option::Option::Some(inner),
+            _ => core::option::Option::None,
+        }
+    }
+    #[allow(unreachable_patterns)]
+    fn as_circle_mut(&mut self) -> core::option::Option<&mut Circle> {
+        match self {
+            Self::Circle(inner) => core::option::Option::Some(inner),
+            _ => core::option::Option::None,
+        }
+    }
+    fn is_circle(&self) -> bool {
+        matches!(self, Self::Circle(_))
+    }
+    #[allow(unreachable_patterns)]
+    fn as_square(&self) -> core::option::Option<&Square> {
+        match self {
+            Self::Square(inner) => core::option::Option::Some(inner),
+            _ => core::option::Option::None,
+        }
+    }
+    #[allow(unreachable_patterns)]
+    fn as_square_mut(&mut self) -> core::option::Option<&mut Square> {
+        match self {
+            Self::Square(inner) => core::option::Option::Some(inner),
+            _ => core::option::Option::None,
+        }
+    }
+    fn is_square(&self) -> bool {
+        matches!(self, Self::Square(_))
+    }
+}
@@ -0,0 +1,59 @@
+struct Name {
+    value: String,
+    #[roopert(get)]
+    len: usize,
+}
+impl core::convert::AsRef<String> for Name {
+    fn as_ref(&self) -> &String {
+        &self.value
+    }
+}
+impl core::convert::AsMut<String> for Name {
+    fn as_mut(&mut self) -> &mut String {
+        &mut self.value
+    }
+}
+impl core::convert::Into<String> for Name {
+    fn into(self) -> String {
+        self.value
+    }
+}
+impl core::ops::Deref for Name {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+impl core::ops::DerefMut for Name {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+impl ::roopert::Upcast<String> for Name {
+    fn upcast(&self) -> &String {
+        &self.value
+    }
+    fn upcast_mut(&mut self) -> &mut String {
+        &mut self.value
+    }
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn roopert_abstract_check(_child: &Name) {
+        use ::roopert::__private::{CheckAbstract as _, CheckConcrete as _};
+        (&::roopert::__private::AbstractCheck::<String, Name>::new()).check();
+    }
+};
+impl ::roopert::Hierarchy for Name
+where
+    Name: 'static,
+{
+    fn type_info(&self) -> &'static ::roopert::TypeInfo {
+        <Self as ::roopert::Hierarchy>::static_type_info()
+    }
+    fn visit_parents(visit: &mut dyn core::ops::FnMut(&'static ::roopert::TypeInfo)) {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{HierarchyTypeInfo as _, LeafTypeInfo as _};
+        visit((&::roopert::__private::TypeInfoOf::<String>::new()).type_info());
+    }
+}
@@ -0,0 +1,15 @@
+struct Base {
+    id: usize,
+}
+impl ::roopert::Hierarchy for Base
+where
+    Base: 'static,
+{
+    fn type_info(&self) -> &'static ::roopert::TypeInfo {
+        <Self as ::roopert::Hierarchy>::static_type_info()
+    }
+    fn visit_parents(visit: &mut dyn core::ops::FnMut(&'static ::roopert::TypeInfo)) {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{HierarchyTypeInfo as _, LeafTypeInfo as _};
+    }
+}
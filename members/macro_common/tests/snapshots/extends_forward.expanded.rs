@@ -0,0 +1,104 @@
+struct Stack<T> {
+    items: Vec<T>,
+}
+impl<T> core::convert::AsRef<Vec<T>> for Stack<T> {
+    fn as_ref(&self) -> &Vec<T> {
+        &self.items
+    }
+}
+impl<T> core::convert::AsMut<Vec<T>> for Stack<T> {
+    fn as_mut(&mut self) -> &mut Vec<T> {
+        &mut self.items
+    }
+}
+impl<T> core::convert::Into<Vec<T>> for Stack<T> {
+    fn into(self) -> Vec<T> {
+        self.items
+    }
+}
+impl<T> core::ops::Deref for Stack<T> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+impl<T> core::ops::DerefMut for Stack<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.items
+    }
+}
+impl<T> ::roopert::Upcast<Vec<T>> for Stack<T> {
+    fn upcast(&self) -> &Vec<T> {
+        &self.items
+    }
+    fn upcast_mut(&mut self) -> &mut Vec<T> {
+        &mut self.items
+    }
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn roopert_abstract_check<T>(_child: &Stack<T>) {
+        use ::roopert::__private::{CheckAbstract as _, CheckConcrete as _};
+        (&::roopert::__private::AbstractCheck::<Vec<T>, Stack<T>>::new()).check();
+    }
+};
+impl<T> ::roopert::Hierarchy for Stack<T>
+where
+    Stack<T>: 'static,
+{
+    fn type_info(&self) -> &'static ::roopert::TypeInfo {
+        <Self as ::roopert::Hierarchy>::static_type_info()
+    }
+    fn visit_parents(visit: &mut dyn core::ops::FnMut(&'static ::roopert::TypeInfo)) {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{HierarchyTypeInfo as _, LeafTypeInfo as _};
+        visit((&::roopert::__private::TypeInfoOf::<Vec<T>>::new()).type_info());
+    }
+}
+impl<T> core::iter::IntoIterator for Stack<T>
+where
+    Vec<T>: core::iter::IntoIterator,
+{
+    type Item = <Vec<T> as core::iter::IntoIterator>::Item;
+    type IntoIter = <Vec<T> as core::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::IntoIterator::into_iter(self.items)
+    }
+}
+impl<'__roopert, T> core::iter::IntoIterator for &'__roopert Stack<T>
+where
+    &'__roopert Vec<T>: core::iter::IntoIterator,
+{
+    type Item = <&'__roopert Vec<T> as core::iter::IntoIterator>::Item;
+    type IntoIter = <&'__roopert Vec<T> as core::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::IntoIterator::into_iter(&self.items)
+    }
+}
+impl<'__roopert, T> core::iter::IntoIterator for &'__roopert mut Stack<T>
+where
+    &'__roopert mut Vec<T>: core::iter::IntoIterator,
+{
+    type Item = <&'__roopert mut Vec<T> as core::iter::IntoIterator>::Item;
+    type IntoIter = <&'__roopert mut Vec<T> as core::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        core::iter::IntoIterator::into_iter(&mut self.items)
+    }
+}
+impl<T, __Idx> core::ops::Index<__Idx> for Stack<T>
+where
+    Vec<T>: core::ops::Index<__Idx>,
+{
+    type Output = <Vec<T> as core::ops::Index<__Idx>>::Output;
+    fn index(&self, index: __Idx) -> &Self::Output {
+        core::ops::Index::index(&self.items, index)
+    }
+}
+impl<T> core::fmt::Debug for Stack<T>
+where
+    Vec<T>: core::fmt::Debug,
+{
+    fn fmt(&self, arg0: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        <Vec<T> as core::fmt::Debug>::fmt(&self.items, arg0)
+    }
+}
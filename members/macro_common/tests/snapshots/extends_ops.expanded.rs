@@ -0,0 +1,106 @@
+struct Meters {
+    value: u32,
+}
+impl core::convert::AsRef<u32> for Meters {
+    fn as_ref(&self) -> &u32 {
+        &self.value
+    }
+}
+impl core::convert::AsMut<u32> for Meters {
+    fn as_mut(&mut self) -> &mut u32 {
+        &mut self.value
+    }
+}
+impl core::convert::Into<u32> for Meters {
+    fn into(self) -> u32 {
+        self.value
+    }
+}
+impl core::ops::Deref for Meters {
+    type Target = u32;
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+impl core::ops::DerefMut for Meters {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+impl ::roopert::Upcast<u32> for Meters {
+    fn upcast(&self) -> &u32 {
+        &self.value
+    }
+    fn upcast_mut(&mut self) -> &mut u32 {
+        &mut self.value
+    }
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn roopert_abstract_check(_child: &Meters) {
+        use ::roopert::__private::{CheckAbstract as _, CheckConcrete as _};
+        (&::roopert::__private::AbstractCheck::<u32, Meters>::new()).check();
+    }
+};
+impl ::roopert::Hierarchy for Meters
+where
+    Meters: 'static,
+{
+    fn type_info(&self) -> &'static ::roopert::TypeInfo {
+        <Self as ::roopert::Hierarchy>::static_type_info()
+    }
+    fn visit_parents(visit: &mut dyn core::ops::FnMut(&'static ::roopert::TypeInfo)) {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{HierarchyTypeInfo as _, LeafTypeInfo as _};
+        visit((&::roopert::__private::TypeInfoOf::<u32>::new()).type_info());
+    }
+}
+impl core::ops::Add for Meters
+where
+    u32: core::ops::Add<Output = u32>,
+{
+    type Output = Self;
+    fn add(self, rhs: Self) -> Self {
+        Self {
+            value: core::ops::Add::add(self.value, rhs.value),
+        }
+    }
+}
+impl core::ops::Add<u32> for Meters
+where
+    u32: core::ops::Add<Output = u32>,
+{
+    type Output = Self;
+    fn add(self, rhs: u32) -> Self {
+        Self {
+            value: core::ops::Add::add(self.value, rhs),
+        }
+    }
+}
+impl core::ops::AddAssign for Meters
+where
+    u32: core::ops::AddAssign,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        core::ops::AddAssign::add_assign(&mut self.value, rhs.value)
+    }
+}
+impl core::ops::AddAssign<u32> for Meters
+where
+    u32: core::ops::AddAssign,
+{
+    fn add_assign(&mut self, rhs: u32) {
+        core::ops::AddAssign::add_assign(&mut self.value, rhs)
+    }
+}
+impl core::ops::Not for Meters
+where
+    u32: core::ops::Not<Output = u32>,
+{
+    type Output = Self;
+    fn not(self) -> Self {
+        Self {
+            value: core::ops::Not::not(self.value),
+        }
+    }
+}
@@ -0,0 +1,58 @@
+struct Wrapper<T: 'static> {
+    inner: T,
+    name: String,
+}
+impl<T: 'static> core::convert::AsRef<String> for Wrapper<T> {
+    fn as_ref(&self) -> &String {
+        &self.name
+    }
+}
+impl<T: 'static> core::convert::AsMut<String> for Wrapper<T> {
+    fn as_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+impl<T: 'static> core::convert::Into<String> for Wrapper<T> {
+    fn into(self) -> String {
+        self.name
+    }
+}
+impl<T: 'static> core::ops::Deref for Wrapper<T> {
+    type Target = String;
+    fn deref(&self) -> &Self::Target {
+        &self.name
+    }
+}
+impl<T: 'static> core::ops::DerefMut for Wrapper<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.name
+    }
+}
+impl<T: 'static> ::roopert::Upcast<String> for Wrapper<T> {
+    fn upcast(&self) -> &String {
+        &self.name
+    }
+    fn upcast_mut(&mut self) -> &mut String {
+        &mut self.name
+    }
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn roopert_abstract_check<T: 'static>(_child: &Wrapper<T>) {
+        use ::roopert::__private::{CheckAbstract as _, CheckConcrete as _};
+        (&::roopert::__private::AbstractCheck::<String, Wrapper<T>>::new()).check();
+    }
+};
+impl<T: 'static> ::roopert::Hierarchy for Wrapper<T>
+where
+    Wrapper<T>: 'static,
+{
+    fn type_info(&self) -> &'static ::roopert::TypeInfo {
+        <Self as ::roopert::Hierarchy>::static_type_info()
+    }
+    fn visit_parents(visit: &mut dyn core::ops::FnMut(&'static ::roopert::TypeInfo)) {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{HierarchyTypeInfo as _, LeafTypeInfo as _};
+        visit((&::roopert::__private::TypeInfoOf::<String>::new()).type_info());
+    }
+}
@@ -0,0 +1,59 @@
+struct Square {
+    #[serde(flatten)]
+    shape: Shape,
+    side: u32,
+}
+impl core::convert::AsRef<Shape> for Square {
+    fn as_ref(&self) -> &Shape {
+        &self.shape
+    }
+}
+impl core::convert::AsMut<Shape> for Square {
+    fn as_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+impl core::convert::Into<Shape> for Square {
+    fn into(self) -> Shape {
+        self.shape
+    }
+}
+impl core::ops::Deref for Square {
+    type Target = Shape;
+    fn deref(&self) -> &Self::Target {
+        &self.shape
+    }
+}
+impl core::ops::DerefMut for Square {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.shape
+    }
+}
+impl ::roopert::Upcast<Shape> for Square {
+    fn upcast(&self) -> &Shape {
+        &self.shape
+    }
+    fn upcast_mut(&mut self) -> &mut Shape {
+        &mut self.shape
+    }
+}
+const _: () = {
+    #[allow(dead_code)]
+    fn roopert_abstract_check(_child: &Square) {
+        use ::roopert::__private::{CheckAbstract as _, CheckConcrete as _};
+        (&::roopert::__private::AbstractCheck::<Shape, Square>::new()).check();
+    }
+};
+impl ::roopert::Hierarchy for Square
+where
+    Square: 'static,
+{
+    fn type_info(&self) -> &'static ::roopert::TypeInfo {
+        <Self as ::roopert::Hierarchy>::static_type_info()
+    }
+    fn visit_parents(visit: &mut dyn core::ops::FnMut(&'static ::roopert::TypeInfo)) {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{HierarchyTypeInfo as _, LeafTypeInfo as _};
+        visit((&::roopert::__private::TypeInfoOf::<Shape>::new()).type_info());
+    }
+}
@@ -0,0 +1,13 @@
+struct Counter {
+    name: String,
+    count: usize,
+}
+impl Counter {
+    pub(crate) fn create(name: impl core::convert::Into<String>) -> Self {
+        let name: String = core::convert::Into::into(name);
+        Self {
+            name,
+            count: core::default::Default::default(),
+        }
+    }
+}
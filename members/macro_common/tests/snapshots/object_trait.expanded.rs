@@ -0,0 +1,35 @@
+trait Widget: ::roopert::AsAny + ::roopert::Hierarchy + ::roopert::Upcast<WidgetBase> {
+    fn draw(&self) -> String;
+}
+#[allow(dead_code)]
+impl dyn Widget {
+    /// Returns true if the trait object is a `__T`
+    fn is<__T: Widget>(&self) -> bool {
+        <dyn Widget as ::roopert::AsAny>::as_any(self).is::<__T>()
+    }
+    /// Returns a reference to the trait object as a `__T`, or `None` if it is a different type
+    fn downcast_ref<__T: Widget>(&self) -> core::option::Option<&__T> {
+        <dyn Widget as ::roopert::AsAny>::as_any(self).downcast_ref::<__T>()
+    }
+    /// Returns a mutable reference to the trait object as a `__T`, or `None` if it is a different type
+    fn downcast_mut<__T: Widget>(&mut self) -> core::option::Option<&mut __T> {
+        <dyn Widget as ::roopert::AsAny>::as_any_mut(self).downcast_mut::<__T>()
+    }
+    ::roopert::__private::with_alloc! {
+        #[doc =
+        r" Converts the boxed trait object into a `Box<__T>`, or returns it unchanged if it is a different type"]
+        fn downcast < __T : Widget > (self : ::roopert::__private::alloc::boxed::Box <
+        Self >) -> core::result::Result < ::roopert::__private::alloc::boxed::Box < __T
+        >, ::roopert::__private::alloc::boxed::Box < Self >> { if self.is:: < __T > () {
+        core::result::Result::Ok(< dyn Widget as ::roopert::AsAny > ::into_any(self)
+        .downcast:: < __T > ().unwrap()) } else { core::result::Result::Err(self) } }
+    }
+    /// Returns a reference to the shared base of the trait object
+    fn as_base(&self) -> &WidgetBase {
+        <dyn Widget as ::roopert::Upcast<WidgetBase>>::upcast(self)
+    }
+    /// Returns a mutable reference to the shared base of the trait object
+    fn as_base_mut(&mut self) -> &mut WidgetBase {
+        <dyn Widget as ::roopert::Upcast<WidgetBase>>::upcast_mut(self)
+    }
+}
@@ -0,0 +1,22 @@
+impl Dog {
+    pub fn speak(&self) -> String {
+        format!("{} (woof)", self.super_speak())
+    }
+}
+impl Dog {
+    #[allow(dead_code)]
+    pub fn super_speak(&self) -> String {
+        <Animal>::speak(core::convert::AsRef::<Animal>::as_ref(self))
+    }
+}
+impl AnimalClass for Dog {
+    fn as_animal(&self) -> &Animal {
+        core::convert::AsRef::<Animal>::as_ref(self)
+    }
+    fn as_animal_mut(&mut self) -> &mut Animal {
+        core::convert::AsMut::<Animal>::as_mut(self)
+    }
+    fn speak(&self) -> String {
+        <Dog>::speak(self)
+    }
+}
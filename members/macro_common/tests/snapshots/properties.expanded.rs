@@ -0,0 +1,88 @@
+#[roopert(accessors, get = All, set = Except(id))]
+struct Setting {
+    id: usize,
+    value: f64,
+}
+impl ::roopert::Properties for Setting {
+    fn properties(&self) -> &'static [&'static str] {
+        &["id", "value"]
+    }
+    fn get_property(
+        &self,
+        name: &str,
+    ) -> core::result::Result<
+        ::roopert::__private::alloc::string::String,
+        ::roopert::PropertyError,
+    > {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{DisplayProperty as _, DisplayUnsupported as _};
+        match name {
+            "id" => {
+                (&::roopert::__private::PropertyValue::<usize>::new())
+                    .display_property(&self.id)
+                    .ok_or(::roopert::PropertyError::Unsupported {
+                        target: "Setting",
+                        property: "id",
+                    })
+            }
+            "value" => {
+                (&::roopert::__private::PropertyValue::<f64>::new())
+                    .display_property(&self.value)
+                    .ok_or(::roopert::PropertyError::Unsupported {
+                        target: "Setting",
+                        property: "value",
+                    })
+            }
+            _ => {
+                core::result::Result::Err(::roopert::PropertyError::UnknownProperty {
+                    target: "Setting",
+                    property: ::roopert::__private::alloc::string::ToString::to_string(
+                        name,
+                    ),
+                })
+            }
+        }
+    }
+    fn set_property(
+        &mut self,
+        name: &str,
+        value: &str,
+    ) -> core::result::Result<(), ::roopert::PropertyError> {
+        #[allow(unused_imports)]
+        use ::roopert::__private::{ParseProperty as _, ParseUnsupported as _};
+        match name {
+            "id" => {
+                core::result::Result::Err(::roopert::PropertyError::ReadOnly {
+                    target: "Setting",
+                    property: "id",
+                })
+            }
+            "value" => {
+                let parsed = (&::roopert::__private::PropertyValue::<f64>::new())
+                    .parse_property(value)
+                    .ok_or(::roopert::PropertyError::Unsupported {
+                        target: "Setting",
+                        property: "value",
+                    })?
+                    .map_err(|message| ::roopert::PropertyError::Parse {
+                        target: "Setting",
+                        property: "value",
+                        value: ::roopert::__private::alloc::string::ToString::to_string(
+                            value,
+                        ),
+                        message,
+                    })?;
+                self.set_value(parsed);
+                core::result::Result::Ok(())
+            }
+            _ => {
+                core::result::Result::Err(::roopert::PropertyError::UnknownProperty {
+                    target: "Setting",
+                    property: ::roopert::__private::alloc::string::ToString::to_string(
+                        name,
+                    ),
+                })
+            }
+        }
+    }
+}
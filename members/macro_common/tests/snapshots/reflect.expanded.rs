@@ -0,0 +1,68 @@
+#[roopert(accessors, get = All)]
+struct Panel {
+    node: Node,
+    title: String,
+}
+impl Panel {
+    /// Static metadata of every field of the struct, in declaration order
+    pub const FIELDS: &'static [::roopert::FieldInfo] = &[
+        ::roopert::FieldInfo {
+            name: "node",
+            type_name: "Node",
+            visibility: ::roopert::FieldVisibility::Private,
+            is_parent: false,
+            has_getter: true,
+            has_setter: false,
+        },
+        ::roopert::FieldInfo {
+            name: "title",
+            type_name: "String",
+            visibility: ::roopert::FieldVisibility::Private,
+            is_parent: false,
+            has_getter: true,
+            has_setter: false,
+        },
+    ];
+}
+impl ::roopert::Reflect for Panel
+where
+    Panel: 'static,
+{
+    fn fields(&self) -> &'static [::roopert::FieldInfo] {
+        Self::FIELDS
+    }
+    fn get_field(&self, name: &str) -> core::option::Option<&dyn core::any::Any> {
+        match name {
+            "node" => core::option::Option::Some(&self.node),
+            "title" => core::option::Option::Some(&self.title),
+            _ => core::option::Option::None,
+        }
+    }
+    #[allow(unused_variables)]
+    fn set_field(
+        &mut self,
+        name: &str,
+        value: ::roopert::__private::alloc::boxed::Box<dyn core::any::Any>,
+    ) -> core::result::Result<(), ::roopert::ReflectError> {
+        match name {
+            "node" => {
+                core::result::Result::Err(::roopert::ReflectError::ReadOnly {
+                    target: "Panel",
+                    field: "node",
+                })
+            }
+            "title" => {
+                core::result::Result::Err(::roopert::ReflectError::ReadOnly {
+                    target: "Panel",
+                    field: "title",
+                })
+            }
+            _ => {
+                core::result::Result::Err(::roopert::ReflectError::UnknownField {
+                    target: "Panel",
+                    field: ::roopert::__private::alloc::string::ToString::to_string(name),
+                })
+            }
+        }
+    }
+}
@@ -16,12 +16,15 @@ use roopert_macro_common::{RoopertAttribute, Generate};
 #[proc_macro_attribute]
 pub fn roopert(attr: TokenStream, item: TokenStream) -> TokenStream {
     //let ast: &DeriveInput = &syn::parse(item.clone()).expect("Unable to parse input target");
-    let mut attr: RoopertAttribute = syn::parse(attr).expect("Unable to parse roopert attribute");
+    let mut attr: RoopertAttribute = match syn::parse(attr) {
+        Ok(attr) => attr,
+        Err(e) => abort!(e)
+    };
     #[cfg(feature="verbose")]
     println!("Parsed roopert attribute: {:?}", attr);
     match attr.generate_auto(item.into()) {
         Ok(stream) => stream.into(),
-        Err(msg) => abort!("{}", msg)
+        Err(e) => abort!(e)
     }
     //let output = quote! {};
     //TokenStream::from(output)
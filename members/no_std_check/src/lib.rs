@@ -0,0 +1,116 @@
+//! Roopert types in a `no_std` crate, to check that generated code only uses `core` and `alloc` paths
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::Display;
+
+use roopert::roopert;
+
+/// Shared state of every shape
+#[roopert(reflect)]
+#[roopert(properties)]
+#[roopert(builder)]
+#[roopert(extends)]
+#[roopert(accessors, get = all, set = all, collection = all)]
+#[derive(Default)]
+pub struct Shape {
+    #[roopert(set, post = self.revision += 1)]
+    name: String,
+    points: Vec<u32>,
+    #[roopert(skip)]
+    #[builder(default)]
+    revision: u32,
+}
+
+/// Abstract methods of every shape
+#[roopert(abstract, Shape)]
+pub trait ShapeMethods {
+    /// Number of sides of the shape
+    fn sides(&self) -> u32;
+}
+
+/// Shapes as trait objects
+#[roopert(object_trait, base = Shape)]
+pub trait AnyShape {}
+
+/// Area of a shape
+#[roopert(delegatable)]
+pub trait Area {
+    /// Area of the shape
+    fn area(&self) -> u32;
+}
+
+/// Shape with four sides
+#[roopert(reflect, parents = nested)]
+#[roopert(new)]
+#[roopert(delegate(core::fmt::Display => label))]
+#[roopert(extends, Shape)]
+pub struct Square {
+    base: Shape,
+    label: &'static str,
+    side: u32,
+}
+
+impl ShapeMethods for Square {
+    fn sides(&self) -> u32 {
+        4
+    }
+}
+
+impl Area for Square {
+    fn area(&self) -> u32 {
+        self.side * self.side
+    }
+}
+
+impl AnyShape for Square {}
+
+/// Shape with three sides
+#[roopert(new)]
+#[roopert(extends, Shape)]
+pub struct Triangle {
+    base: Shape,
+    base_length: u32,
+    height: u32,
+}
+
+impl ShapeMethods for Triangle {
+    fn sides(&self) -> u32 {
+        3
+    }
+}
+
+impl Area for Triangle {
+    fn area(&self) -> u32 {
+        self.base_length * self.height / 2
+    }
+}
+
+impl AnyShape for Triangle {}
+
+/// Every concrete shape
+#[roopert(dispatch(Area))]
+pub enum Shapes {
+    /// Four sides
+    Square(Square),
+    /// Three sides
+    Triangle(Triangle),
+}
+
+/// Label of anything which can be displayed
+pub fn label_len(value: &impl Display) -> usize {
+    use core::fmt::Write;
+    struct Counter(usize);
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+    let mut counter = Counter(0);
+    write!(counter, "{}", value).unwrap();
+    counter.0
+}
@@ -0,0 +1,40 @@
+//! Generated code in a no_std crate
+
+use roopert::{roopert, HierarchyExt, Properties, Reflect};
+use roopert_no_std_check::{label_len, Area, AnyShape, Shape, ShapeMethods, Shapes, Square, Triangle};
+
+// the delegatable trait is defined by another crate
+#[roopert(delegate(Area => square))]
+struct RoopertFramed {
+    square: Square,
+    frame: u32,
+}
+
+#[test]
+fn no_std_test() {
+    let mut base = Shape::builder().name("square".to_string()).points(vec![0, 1, 2, 3]).build().unwrap();
+    base.push_point(4);
+    assert_eq!(base.get_points().len(), 5);
+    let mut square = Square::new(base, "big square", 3);
+    assert_eq!(square.sides(), 4);
+    assert_eq!(square.area(), 9);
+    assert_eq!(label_len(&square), 10);
+    assert!(square.is_a::<Shape>());
+    square.set_property("name", "renamed").unwrap();
+    assert_eq!(square.get_property("name").unwrap(), "renamed");
+    assert_eq!(square.get_field("base.name").unwrap().downcast_ref::<String>().unwrap(), "renamed");
+    let boxed: Box<dyn AnyShape> = Box::new(square);
+    assert_eq!(boxed.as_base().get_name(), "renamed");
+    assert!(boxed.downcast::<Square>().is_ok());
+    let triangle = Triangle::new(Shape::default(), 4, 3);
+    assert_eq!(triangle.sides(), 3);
+    let shapes: Shapes = triangle.into();
+    assert_eq!(shapes.area(), 6);
+}
+
+#[test]
+fn delegate_other_crate_test() {
+    let framed = RoopertFramed { square: Square::new(Shape::default(), "framed", 5), frame: 1 };
+    assert_eq!(framed.area(), 25);
+    assert_eq!(framed.frame, 1);
+}
@@ -0,0 +1,220 @@
+//! Implementation details of generated code. Not public API.
+
+use core::marker::PhantomData;
+
+/// Alloc, for the `Box`, `String` and `Vec` paths of generated code.
+#[cfg(feature = "alloc")]
+pub extern crate alloc;
+
+/// Serde, for the code generated with the `serde` feature.
+#[cfg(feature = "serde")]
+pub use serde;
+
+#[cfg(feature = "alloc")]
+use alloc::string::{String, ToString};
+
+/// Error of deserializing a struct through its setters, when a setter hook panicked.
+#[cfg(all(feature = "serde", feature = "std"))]
+#[derive(Debug)]
+pub struct SetterHookError(String);
+
+/// Error of deserializing a struct through its setters, which cannot happen without the `std` feature since hook panics are not caught.
+#[cfg(all(feature = "serde", not(feature = "std")))]
+#[derive(Debug)]
+pub enum SetterHookError {}
+
+#[cfg(feature = "serde")]
+impl core::fmt::Display for SetterHookError {
+    #[cfg(feature = "std")]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "setter hook failed: {}", self.0)
+    }
+
+    #[cfg(not(feature = "std"))]
+    fn fmt(&self, _f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {}
+    }
+}
+
+/// Run the setters of a deserialized struct, turning a panic of a setter hook into an error.
+#[cfg(all(feature = "serde", feature = "std"))]
+pub fn run_setter_hooks<T>(set: impl FnOnce() -> T) -> Result<T, SetterHookError> {
+    std::panic::catch_unwind(std::panic::AssertUnwindSafe(set)).map_err(|payload| {
+        let message = payload.downcast_ref::<&str>().map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "panicked".to_string());
+        SetterHookError(message)
+    })
+}
+
+/// Run the setters of a deserialized struct (a panic of a setter hook is not caught without the `std` feature).
+#[cfg(all(feature = "serde", not(feature = "std")))]
+pub fn run_setter_hooks<T>(set: impl FnOnce() -> T) -> Result<T, SetterHookError> {
+    Ok(set())
+}
+
+/// Expands to the items only when the `alloc` feature is enabled, for generated code which uses `Box`.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __roopert_with_alloc {
+    ($($items:tt)*) => {
+        $($items)*
+    };
+}
+
+/// Expands to the items only when the `alloc` feature is enabled, for generated code which uses `Box`.
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __roopert_with_alloc {
+    ($($items:tt)*) => {};
+}
+
+pub use crate::__roopert_with_alloc as with_alloc;
+
+/// Implemented by the `abstract` attribute for abstract base types.
+pub trait IsAbstract {}
+
+/// Implemented by the `abstract` attribute for every child type which implements the abstract methods.
+pub trait AbstractBase<C: ?Sized> {}
+
+/// Compile-time check that a child of an abstract base implements the abstract methods.
+pub struct AbstractCheck<P: ?Sized, C: ?Sized>(PhantomData<(*const P, *const C)>);
+
+impl<P: ?Sized, C: ?Sized> AbstractCheck<P, C> {
+    /// Create a new check
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<P: ?Sized, C: ?Sized> Default for AbstractCheck<P, C> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Selected when the parent is abstract (by-value receiver), which requires the child to implement the abstract methods.
+pub trait CheckAbstract<P: ?Sized, C: ?Sized> {
+    /// Perform the check
+    fn check(&self) where P: AbstractBase<C> {}
+}
+
+impl<P: IsAbstract + ?Sized, C: ?Sized> CheckAbstract<P, C> for AbstractCheck<P, C> {}
+
+/// Selected when the parent is not abstract (autoref receiver), which always passes.
+pub trait CheckConcrete {
+    /// Perform the check
+    fn check(&self) {}
+}
+
+impl<P: ?Sized, C: ?Sized> CheckConcrete for &AbstractCheck<P, C> {}
+
+/// Type information lookup for a parent type, which may or may not be part of a roopert hierarchy.
+pub struct TypeInfoOf<T: ?Sized>(PhantomData<*const T>);
+
+impl<T: ?Sized> TypeInfoOf<T> {
+    /// Create a new lookup
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for TypeInfoOf<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Selected when the parent is part of a roopert hierarchy (by-value receiver).
+pub trait HierarchyTypeInfo {
+    /// Type information of the parent, including its own parents
+    fn type_info(&self) -> &'static crate::TypeInfo;
+}
+
+impl<T: crate::Hierarchy> HierarchyTypeInfo for TypeInfoOf<T> {
+    fn type_info(&self) -> &'static crate::TypeInfo {
+        T::static_type_info()
+    }
+}
+
+/// Selected when the parent is not part of a roopert hierarchy (autoref receiver).
+pub trait LeafTypeInfo {
+    /// Type information of the parent, without parents
+    fn type_info(&self) -> &'static crate::TypeInfo;
+}
+
+impl<T: ?Sized + 'static> LeafTypeInfo for &TypeInfoOf<T> {
+    fn type_info(&self) -> &'static crate::TypeInfo {
+        crate::hierarchy::LeafInfo::<T>::type_info()
+    }
+}
+
+/// String conversion of a property type, which may or may not implement `FromStr` and `Display`.
+pub struct PropertyValue<T: ?Sized>(PhantomData<*const T>);
+
+impl<T: ?Sized> PropertyValue<T> {
+    /// Create a new conversion
+    pub const fn new() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: ?Sized> Default for PropertyValue<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Selected when the property type implements `FromStr` (by-value receiver).
+#[cfg(feature = "alloc")]
+pub trait ParseProperty<T> {
+    /// Parse the value, or `None` if the type cannot be parsed
+    fn parse_property(&self, value: &str) -> Option<Result<T, String>>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: core::str::FromStr> ParseProperty<T> for PropertyValue<T> where T::Err: core::fmt::Display {
+    fn parse_property(&self, value: &str) -> Option<Result<T, String>> {
+        Some(value.parse::<T>().map_err(|e| e.to_string()))
+    }
+}
+
+/// Selected when the property type does not implement `FromStr` (autoref receiver).
+#[cfg(feature = "alloc")]
+pub trait ParseUnsupported<T> {
+    /// Parse the value, or `None` if the type cannot be parsed
+    fn parse_property(&self, _value: &str) -> Option<Result<T, String>> {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ParseUnsupported<T> for &PropertyValue<T> {}
+
+/// Selected when the property type implements `Display` (by-value receiver).
+#[cfg(feature = "alloc")]
+pub trait DisplayProperty<T: ?Sized> {
+    /// Format the value, or `None` if the type cannot be formatted
+    fn display_property(&self, value: &T) -> Option<String>;
+}
+
+#[cfg(feature = "alloc")]
+impl<T: core::fmt::Display + ?Sized> DisplayProperty<T> for PropertyValue<T> {
+    fn display_property(&self, value: &T) -> Option<String> {
+        Some(value.to_string())
+    }
+}
+
+/// Selected when the property type does not implement `Display` (autoref receiver).
+#[cfg(feature = "alloc")]
+pub trait DisplayUnsupported<T: ?Sized> {
+    /// Format the value, or `None` if the type cannot be formatted
+    fn display_property(&self, _value: &T) -> Option<String> {
+        None
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> DisplayUnsupported<T> for &PropertyValue<T> {}
@@ -0,0 +1,24 @@
+use core::fmt::{Display, Formatter};
+
+/// Error returned by a generated builder's `build()` function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildError {
+    /// A required field was not supplied before building.
+    MissingField {
+        /// Name of the struct being built
+        target: &'static str,
+        /// Name of the missing field
+        field: &'static str,
+    },
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::MissingField { target, field } => write!(f, "Cannot build {}: missing required field {}", target, field),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
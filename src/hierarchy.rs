@@ -0,0 +1,168 @@
+//! Runtime type information for types which use the `extends` attribute.
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::any::TypeId;
+use core::marker::PhantomData;
+
+/// Static information about a type in a roopert hierarchy.
+pub struct TypeInfo {
+    name: fn() -> &'static str,
+    type_id: fn() -> TypeId,
+    parents: fn(&mut dyn FnMut(&'static TypeInfo)),
+}
+
+impl TypeInfo {
+    /// The name of the type, as returned by `core::any::type_name`
+    pub fn name(&self) -> &'static str {
+        (self.name)()
+    }
+
+    /// The `TypeId` of the type
+    pub fn type_id(&self) -> TypeId {
+        (self.type_id)()
+    }
+
+    /// The types directly extended by the type, in declaration order
+    #[cfg(feature = "alloc")]
+    pub fn parents(&self) -> Vec<&'static TypeInfo> {
+        let mut parents = Vec::new();
+        (self.parents)(&mut |parent| parents.push(parent));
+        parents
+    }
+
+    /// Every type extended by the type, directly or indirectly, in depth-first order without duplicates
+    #[cfg(feature = "alloc")]
+    pub fn ancestors(&self) -> Vec<&'static TypeInfo> {
+        let mut ancestors: Vec<&'static TypeInfo> = Vec::new();
+        let mut stack = self.parents();
+        stack.reverse();
+        while let Some(info) = stack.pop() {
+            if ancestors.iter().any(|known| known.type_id() == info.type_id()) {
+                continue;
+            }
+            ancestors.push(info);
+            let mut parents = info.parents();
+            parents.reverse();
+            stack.extend(parents);
+        }
+        ancestors
+    }
+
+    /// Returns true if the type is `T` or extends `T`, directly or indirectly
+    pub fn is_a<T: ?Sized + 'static>(&self) -> bool {
+        self.is_a_type_id(TypeId::of::<T>())
+    }
+
+    /// Returns true if the type has the `TypeId` or extends a type with the `TypeId`, directly or indirectly
+    pub fn is_a_type_id(&self, type_id: TypeId) -> bool {
+        if self.type_id() == type_id {
+            return true;
+        }
+        let mut found = false;
+        (self.parents)(&mut |parent| found = found || parent.is_a_type_id(type_id));
+        found
+    }
+}
+
+impl PartialEq for TypeInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.type_id() == other.type_id()
+    }
+}
+
+impl Eq for TypeInfo {}
+
+impl core::fmt::Debug for TypeInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypeInfo")
+            .field("name", &self.name())
+            .field("parents", &DebugParents(self))
+            .finish()
+    }
+}
+
+// lists the parents without collecting them
+struct DebugParents<'a>(&'a TypeInfo);
+
+impl core::fmt::Debug for DebugParents<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut list = f.debug_list();
+        (self.0.parents)(&mut |parent| {
+            list.entry(parent);
+        });
+        list.finish()
+    }
+}
+
+/// A type in a roopert hierarchy.
+/// This is implemented by the `extends` attribute, and can be used as a trait object.
+pub trait Hierarchy: core::any::Any {
+    /// Runtime type information of the concrete type
+    fn type_info(&self) -> &'static TypeInfo;
+
+    /// Runtime type information of the type
+    fn static_type_info() -> &'static TypeInfo where Self: Sized {
+        &InfoOf::<Self>::INFO
+    }
+
+    /// Call `visit` with the type information of each type directly extended by the type
+    #[doc(hidden)]
+    fn visit_parents(visit: &mut dyn FnMut(&'static TypeInfo)) where Self: Sized;
+}
+
+/// Runtime type checks for types in a roopert hierarchy, including trait objects.
+pub trait HierarchyExt {
+    /// Returns true if the object is a `T` or extends `T`, directly or indirectly
+    fn is_a<T: ?Sized + 'static>(&self) -> bool;
+
+    /// The name of the concrete type of the object
+    fn type_name(&self) -> &'static str;
+
+    /// Every type extended by the object, directly or indirectly, in depth-first order without duplicates
+    #[cfg(feature = "alloc")]
+    fn ancestors(&self) -> Vec<&'static TypeInfo>;
+}
+
+impl<H: Hierarchy + ?Sized> HierarchyExt for H {
+    fn is_a<T: ?Sized + 'static>(&self) -> bool {
+        self.type_info().is_a::<T>()
+    }
+
+    fn type_name(&self) -> &'static str {
+        self.type_info().name()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn ancestors(&self) -> Vec<&'static TypeInfo> {
+        self.type_info().ancestors()
+    }
+}
+
+// promoted to a static for every type
+struct InfoOf<T: ?Sized>(PhantomData<*const T>);
+
+impl<T: Hierarchy> InfoOf<T> {
+    const INFO: TypeInfo = TypeInfo {
+        name: core::any::type_name::<T>,
+        type_id: TypeId::of::<T>,
+        parents: T::visit_parents,
+    };
+}
+
+// type information for types which are not part of a roopert hierarchy
+pub(crate) struct LeafInfo<T: ?Sized>(PhantomData<*const T>);
+
+impl<T: ?Sized + 'static> LeafInfo<T> {
+    const INFO: TypeInfo = TypeInfo {
+        name: core::any::type_name::<T>,
+        type_id: TypeId::of::<T>,
+        parents: no_parents,
+    };
+
+    pub(crate) fn type_info() -> &'static TypeInfo {
+        &Self::INFO
+    }
+}
+
+fn no_parents(_visit: &mut dyn FnMut(&'static TypeInfo)) {}
@@ -2,8 +2,8 @@
 //! Spend less time writing boilerplate and more time implementing functionality! 
 //! 
 //! ## Attributes
-//! | [parent](#parent) | [extends](#extends) | [accessors](#accessors) | [get](#get) | [set](#set) |
-//! | --- | --- | --- | --- | --- |
+//! | [parent](#parent) | [extends](#extends) | [accessors](#accessors) | [get](#get) | [set](#set) | [skip](#skip) | [collection](#collection) | [new](#new) | [builder](#builder) | [class](#class) | [overrides](#overrides) | [abstract](#abstract) | [delegate](#delegate) | [object_trait](#object_trait) | [dispatch](#dispatch) | [reflect](#reflect) | [properties](#properties) | [crate](#crate) |
+//! | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- | --- |
 //!
 //! ### parent
 //! A field-level attribute for indicating which field is the parent of the struct in conjunction with the `extends` attribute macro.
@@ -44,11 +44,89 @@
 //! # fn main() {}
 //! ```
 //!
+//! Trait implementations of the parent are not available through `Deref`, but standard library traits can be forwarded to the parent field
+//! with a `forward(Trait, ...)` parameter after the parent type.
+//! The supported traits are `Display`, `Debug`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `IntoIterator` (for the struct, `&` struct and `&mut` struct),
+//! `Index`, `IndexMut`, `Extend`, `Iterator`, `io::Write`, `fmt::Write` and `io::Read`.
+//! `Write` must be written as `fmt::Write` or `io::Write` (or with its full path), since the name alone is ambiguous.
+//! Forwarded comparisons and hashes only use the parent field, so other fields are ignored.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(extends, Vec<u32>, forward(Debug, PartialEq, Index, IntoIterator))]
+//! struct MyList {
+//!     items: Vec<u32>,
+//! }
+//!
+//! # fn main() {
+//! let list = MyList { items: vec![1, 2, 3] };
+//! assert_eq!(list[1], 2);
+//! assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+//! assert_eq!(list.into_iter().sum::<u32>(), 6);
+//! # }
+//! ```
+//!
+//! Operators can be implemented by operating on the parent field with an `ops` parameter after the parent type,
+//! for `Add`, `Sub`, `Mul`, `Div`, `Rem` and their compound assignments (`AddAssign`, etc.),
+//! or with `ops(Operator, ...)` for specific operators (including `Neg` for signed and floating point parents, and `BitAnd`, `BitOr`, `BitXor`, `Shl`, `Shr`, `Not` and their compound assignments).
+//! The right hand side of binary operators and compound assignments can be the struct or the parent type.
+//! The other fields of the result of a binary or unary operator are taken from the left hand side (`rest = lhs`, the default),
+//! or initialised with `Default::default()` when `rest = default` is supplied; compound assignments only change the parent field.
+//! Comparisons can be forwarded with `forward(PartialEq, PartialOrd)`.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(extends, f64, ops, forward(PartialEq, PartialOrd))]
+//! struct Meters {
+//!     meters: f64,
+//! }
+//!
+//! # fn main() {
+//! let mut distance = Meters { meters: 1.0 } + Meters { meters: 2.0 };
+//! distance *= 2.0;
+//! assert!(distance == Meters { meters: 6.0 });
+//! # }
+//! ```
+//!
+//! The `extends` attribute also implements `roopert::Hierarchy`, which provides runtime type information (the type's name, `TypeId` and parents).
+//! Parent types which do not use `extends` are included without parents of their own, and `#[roopert(extends)]` without parent types makes a root type part of a hierarchy.
+//! `HierarchyExt` provides `is_a::<T>()`, `type_name()` and `ancestors()` for hierarchy types, including `dyn Hierarchy` trait objects.
+//!
+//! ```
+//! # use roopert::roopert;
+//! use roopert::{Hierarchy, HierarchyExt};
+//!
+//! #[roopert(extends)]
+//! struct Animal {
+//!     legs: usize,
+//! }
+//!
+//! #[roopert(extends, Animal)]
+//! struct Dog {
+//!     animal: Animal,
+//! }
+//!
+//! # fn main() {
+//! let animals: Vec<Box<dyn Hierarchy>> = vec![
+//!     Box::new(Animal { legs: 2 }),
+//!     Box::new(Dog { animal: Animal { legs: 4 } }),
+//! ];
+//! assert!(animals.iter().all(|animal| animal.is_a::<Animal>()));
+//! assert!(!animals[0].is_a::<Dog>());
+//! assert!(animals[1].is_a::<Dog>());
+//! assert_eq!(animals[1].ancestors()[0].name(), std::any::type_name::<Animal>());
+//! # }
+//! ```
+//!
 //! ### accessors
 //! A struct-level attribute for automatically creating getters and setters for fields of a named struct.
-//! Optionally, this attribute accepts one or two parameters (in any order): `get = rule` and `set = rule`,
-//! where rule can be one of `All` (generate all accessors), `Private` (generate accessors for all private fields), `No` (don't generate -- default).
-//! Additionally, the get and set attribute types can be used to override the struct-level getter and setter rule, respectively.
+//! Optionally, this attribute accepts up to three parameters (in any order): `get = rule`, `set = rule` and `collection = rule`,
+//! where rule can be one of `All` (generate all accessors), `Private` (generate accessors for all private fields),
+//! `PubCrate` (generate accessors for all fields which are not `pub`, e.g. private and `pub(crate)` fields),
+//! `Except(field, ...)` (generate accessors for all fields except the listed ones), `No` (don't generate -- default).
+//! Additionally, the get and set attribute types can be used to override the struct-level getter and setter rule, respectively,
+//! and the skip attribute type can be used to exclude a field from all rules.
+//! The collection rule only applies to fields of a `Vec`, `HashMap`, `BTreeMap`, `HashSet` or `BTreeSet` type (see [collection](#collection)).
 //!
 //! ```
 //! # use roopert::roopert;
@@ -80,6 +158,7 @@
 //! Optionally, `pre = operation` can be supplied to do an operation before the get function returns.
 //! The optional parameter `mutable = true` can be supplied to get a mutable reference (as well as allow mutable `self` operations with the pre parameter).
 //! The optional parameter `name = "getter_name"` can be used to specify a custom get function name (defaults to `get_<field name>`).
+//! The optional parameter `skip` can be supplied to prevent a getter from being generated for the field by the struct-level rule.
 //! The standard form `#[roopert(get)]` or the shortened `#[get]` attribute macro may be used.
 //! **Note**: this doesn't work without `#[roopert(accessors)]` on the struct.
 //!
@@ -115,6 +194,9 @@
 //! A field-level attribute for overriding accessors attribute behaviour for setter methods.
 //! Optionally, `pre = operation` and `post = operation` can be used to do an operation before and after the variable is set, respectively.
 //! The optional parameter `name = "setter_name"` can be used to specify a custom set function name (defaults to `set_<field name>`).
+//! The optional parameter `skip` can be supplied to prevent a setter from being generated for the field by the struct-level rule.
+//! The optional parameters `replace`, `take` and `update` generate `replace_<field name>(x) -> old`, `take_<field name>() -> old` (for `Default` types)
+//! and `update_<field name>(|field| ...)` functions alongside the setter, which run the same pre and post operations around the change.
 //! The standard form `#[roopert(set)]` or shortened the `#[set]` attribute macro may be used.
 //! **Note**: this doesn't work without `#[roopert(accessors)]` on the struct.
 //! 
@@ -150,8 +232,646 @@
 //! # fn main() {}
 //! ```
 //!
+//! ### skip
+//! A field-level attribute for excluding a field from the struct-level `get` and `set` rules of the accessors attribute.
+//! This attribute macro takes no other parameters, and explicit get and set attributes on the same field still apply.
+//! The standard form `#[roopert(skip)]` attribute macro is used.
+//! **Note**: this doesn't work without `#[roopert(accessors)]` on the struct.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(accessors, get = All, set = All)]
+//! struct MyStruct {
+//!     my_field: String,
+//!     #[roopert(skip)]
+//!     my_secret: String,
+//! }
+//!
+//! // automatically generated by Roopert
+//! # /*
+//! impl MyStruct {
+//!     pub fn get_my_field(&self) -> &String {
+//!         &self.my_field
+//!     }
+//!
+//!     pub fn set_my_field(&mut self, x: String) {
+//!         self.my_field = x;
+//!     }
+//!     // nothing is generated for my_secret
+//! }
+//! # */
+//! # fn main() {}
+//! ```
+//!
+//! ### collection
+//! A field-level attribute for generating collection accessors instead of plain getters and setters.
+//! Supported field types are `Vec<T>`, `HashMap<K, V>`, `BTreeMap<K, V>`, `HashSet<T>` and `BTreeSet<T>`.
+//! For a field `items`, a read-only view `items()` (a slice for `Vec`), `items_iter()` and `clear_items()` are generated, along with
+//! `push_item(x)` and `remove_item(index)` for lists, `insert_item(key, value)`, `get_item(&key)` and `remove_item(&key)` for maps,
+//! and `insert_item(x)`, `contains_item(&x)` and `remove_item(&x)` for sets.
+//! Optionally, `pre = operation` and `post = operation` can be used to do an operation before and after the collection is modified, respectively.
+//! The optional parameter `singular = "item"` can be used to specify the singular name used in generated function names (defaults to the field name without a trailing `s`, or `es` for plurals like `addresses`, `boxes` and `matches`, or `ies` replaced by `y`, except for common words like `caches` and `movies`;
+//! use `singular` for any other plural which is not named correctly).
+//! The optional parameter `skip` can be supplied to prevent collection accessors from being generated for the field by the struct-level rule.
+//! The standard form `#[roopert(collection)]` or the shortened `#[collection]` attribute macro may be used.
+//! **Note**: this doesn't work without `#[roopert(accessors)]` on the struct.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(accessors)]
+//! struct MyStruct {
+//!     #[roopert(collection, post = self.after_change())]
+//!     entries: Vec<String>,
+//!     changes: usize,
+//! }
+//!
+//! impl MyStruct {
+//!     fn after_change(&mut self) {
+//!         self.changes += 1;
+//!     }
+//! }
+//!
+//! // automatically generated by Roopert
+//! # /*
+//! impl MyStruct {
+//!     pub fn entries(&self) -> &[String] {
+//!         &self.entries
+//!     }
+//!
+//!     pub fn push_entry(&mut self, x: String) {
+//!         self.entries.push(x);
+//!         self.after_change(); // from `post = self.after_change()`
+//!     }
+//!
+//!     // (not shown: entries_iter, remove_entry and clear_entries)
+//! }
+//! # */
+//! # fn main() {}
+//! ```
+//!
+//! ### new
+//! A struct-level attribute for automatically creating a constructor for a named struct.
+//! The constructor takes every field as a parameter, in declaration order, except for fields with a field-level `default` or `value = expression` parameter.
+//! Optionally, `name = "constructor_name"` can be used to specify a custom constructor name (defaults to `new`),
+//! `vis = "pub(crate)"` can be used to specify the constructor visibility (defaults to `pub`),
+//! and `into` can be supplied to accept `impl Into<T>` parameters for all fields.
+//! On fields, the `default` parameter initialises the field with `Default::default()`, the `value = expression` parameter initialises the field with an expression
+//! (which can use any constructor parameter or previously initialised field), and the `into` parameter accepts an `impl Into<T>` parameter for the field.
+//! The standard form `#[roopert(new)]` attribute macro is used on the struct, and the standard form `#[roopert(new, ...)]` or the shortened `#[new(...)]` attribute macro may be used on fields.
+//! Parameters which only apply to the struct (`name`, `vis`) or only to fields (`default`, `value`) are reported as errors at the other level.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(new, into)]
+//! struct MyStruct {
+//!     my_field: String,
+//!     #[new(default)]
+//!     my_history: Vec<String>,
+//!     #[new(value = my_field.len())]
+//!     my_length: usize,
+//! }
+//!
+//! // automatically generated by Roopert
+//! # /*
+//! impl MyStruct {
+//!     pub fn new(my_field: impl Into<String>) -> Self {
+//!         let my_field: String = Into::into(my_field);
+//!         let my_length: usize = my_field.len();
+//!         Self {
+//!             my_field,
+//!             my_history: Default::default(),
+//!             my_length,
+//!         }
+//!     }
+//! }
+//! # */
+//! # fn main() {}
+//! ```
+//!
+//! ### builder
+//! A struct-level attribute for automatically creating a builder for a named struct.
+//! The builder is named `<struct name>Builder` and has a fluent function for every field, a `build()` function which returns `Result<Struct, roopert::BuildError>`,
+//! and is also created by the generated `Struct::builder()` function.
+//! Fields are required unless they have a field-level `default` parameter (initialise with `Default::default()`) or `default = expression` parameter.
+//! Optionally, `name = "BuilderName"` can be used to specify a custom builder name and `into` can be supplied to accept `impl Into<T>` for all fields.
+//! On fields, the `into` parameter accepts `impl Into<T>` in the fluent function for the field.
+//! The builder attribute must be placed above the `extends` attribute, which reports an error otherwise,
+//! and the builder then also implements `From<Parent>` to start building from a pre-built parent.
+//! The optional parameter `typestate` makes the builder check required fields at compile time instead:
+//! each required field is tracked by a generic parameter of the builder (`<builder name><Field>Missing` or `<builder name><Field>Set` marker types),
+//! and `build()` only exists (and returns the struct directly) once every required field was supplied.
+//! The standard form `#[roopert(builder)]` attribute macro is used on the struct, and the standard form `#[roopert(builder, ...)]` or the shortened `#[builder(...)]` attribute macro may be used on fields.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(builder)]
+//! #[roopert(extends, String)]
+//! struct MyStruct {
+//!     my_parent: String,
+//!     my_field: usize,
+//!     #[builder(default = 42)]
+//!     my_answer: u32,
+//! }
+//!
+//! # fn main() {
+//! let built = MyStruct::builder()
+//!     .my_parent("parent".to_string())
+//!     .my_field(1)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(built.my_answer, 42);
+//!
+//! let from_parent = MyStructBuilder::from("parent".to_string()).build();
+//! assert!(from_parent.is_err()); // my_field is required
+//! # }
+//! ```
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(builder, typestate)]
+//! struct MyShape {
+//!     width: u32,
+//!     height: u32,
+//! }
+//!
+//! # fn main() {
+//! let shape = MyShape::builder().width(1).height(2).build(); // no unwrap required
+//! // MyShape::builder().width(1).build(); // does not compile: MyShapeBuilder<MyShapeBuilderWidthSet, MyShapeBuilderHeightMissing> has no build()
+//! assert_eq!(shape.height, 2);
+//! # }
+//! ```
+//!
+//! ### class
+//! An impl-level attribute for declaring the methods of a type which can be overridden by its children.
+//! The attribute generates a trait named `<type name>Class` with an `as_<type name>(&self)` and `as_<type name>_mut(&mut self)` function,
+//! plus a default implementation of every `&self` and `&mut self` method in the impl block (except methods which use `Self` in their signature, which cannot be overridden), which calls the type's own method.
+//! The trait is implemented for the type, so callers can use `&dyn <type name>Class` (or a generic parameter) to accept the type or any of its children.
+//! Optionally, `name = "TraitName"` can be used to specify a custom trait name and `vis = "pub(crate)"` can be used to specify the trait visibility (defaults to `pub`).
+//! The standard form `#[roopert(class)]` attribute macro is used on the impl block.
+//!
+//! ### overrides
+//! An impl-level attribute for implementing the class trait of a parent type (see `class`), in conjunction with the `extends` attribute macro.
+//! Methods marked with `#[roopert(override)]` replace the parent's implementation when called through the class trait,
+//! and a `super_<method name>` function is generated for every overridden method to call the parent's implementation.
+//! All other methods of the class trait keep calling the parent's implementation.
+//! Only calls through the class trait dispatch to the override: calls inside the parent's own methods go to the parent's implementation,
+//! since the methods of the impl block are not changed by the `class` attribute.
+//! Optionally, `class = TraitName<...>` can be used to specify the class trait when the parent's trait has a custom name.
+//! The standard form `#[roopert(overrides, ParentType)]` attribute macro is used on the impl block, and the standard form `#[roopert(override)]` attribute macro is used on methods
+//! (`override` is a reserved keyword in Rust, so it cannot be used as an attribute on its own).
+//!
+//! ```
+//! # use roopert::roopert;
+//! struct Animal {
+//!     name: String,
+//! }
+//!
+//! #[roopert(class)]
+//! impl Animal {
+//!     pub fn speak(&self) -> String {
+//!         format!("{} makes a sound", self.name)
+//!     }
+//! }
+//!
+//! #[roopert(extends, Animal)]
+//! struct Dog {
+//!     animal: Animal,
+//! }
+//!
+//! #[roopert(overrides, Animal)]
+//! impl Dog {
+//!     #[roopert(override)]
+//!     pub fn speak(&self) -> String {
+//!         format!("{} (woof)", self.super_speak())
+//!     }
+//! }
+//!
+//! fn speak(animal: &dyn AnimalClass) -> String {
+//!     animal.speak()
+//! }
+//!
+//! # fn main() {
+//! let dog = Dog { animal: Animal { name: "Rex".to_string() } };
+//! assert_eq!(speak(&dog), "Rex makes a sound (woof)");
+//! assert_eq!(speak(dog.as_animal()), "Rex makes a sound");
+//! # }
+//! ```
+//!
+//! ### abstract
+//! A trait-level attribute for declaring the abstract methods of a base type, which holds the shared state (fields) of all of its children.
+//! Every struct which extends the base type with the `extends` attribute macro fails to compile unless it also implements the trait,
+//! while the concrete methods of the base type are still available through the `Deref` implementation of `extends`.
+//! The trait automatically requires `AsRef<BaseType>`, so default methods in the trait can use the shared state and the abstract methods together.
+//! The trait gets a `#[diagnostic::on_unimplemented]` attribute (which needs Rust 1.78 or newer), so a child without an implementation fails with
+//! "`Child` extends abstract `BaseType` but does not implement its abstract methods".
+//! The standard form `#[roopert(abstract, BaseType)]` attribute macro is used on the trait.
+//!
+//! ```
+//! # use roopert::roopert;
+//! struct Shape {
+//!     name: String,
+//! }
+//!
+//! #[roopert(abstract, Shape)]
+//! trait ShapeMethods {
+//!     fn area(&self) -> f64;
+//!
+//!     fn describe(&self) -> String {
+//!         format!("{} with area {}", self.as_ref().name, self.area())
+//!     }
+//! }
+//!
+//! #[roopert(extends, Shape)]
+//! struct Square {
+//!     shape: Shape,
+//!     side: f64,
+//! }
+//!
+//! // removing this implementation causes a compile error: the trait `ShapeMethods` is not implemented for `Square`
+//! impl ShapeMethods for Square {
+//!     fn area(&self) -> f64 {
+//!         self.side * self.side
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let square = Square { shape: Shape { name: "square".to_string() }, side: 2.0 };
+//! assert_eq!(square.describe(), "square with area 4");
+//! # }
+//! ```
+//!
+//! ### delegate
+//! A struct-level attribute for implementing a trait by forwarding every method to a field, since `Deref` does not forward trait implementations.
+//! Every method, associated type and associated constant of the trait is forwarded to the field's implementation of the trait,
+//! including generic methods; the methods of a trait can not use the `Self` type (except for associated items like `Self::Item`).
+//! Generic traits are implemented for the supplied generic arguments (`Trait<u32> => field`), or for every generic argument which the field implements (`Trait => field`).
+//! The standard library traits `core::fmt::Display`, `core::fmt::Debug`, `core::iter::Iterator`, `std::io::Write`, `std::io::Read` and `core::fmt::Write` can be delegated directly
+//! when they are written with their full path (`std::fmt::Display` also works), so a trait of the crate can share the name of a standard library trait.
+//! Any other path (including a standard library trait without its full path) refers to a trait declared with the trait-level `#[roopert(delegatable)]` attribute, which exports the trait definition as a macro with the same name as the trait,
+//! so the trait must be in scope (and the types used by its methods must be in scope) wherever it is delegated.
+//! The macro is exported along with the trait, so traits of other crates can be delegated too.
+//! The standard form `#[roopert(delegate(Trait => field, ...))]` attribute macro is used on the struct.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(delegatable)]
+//! trait Greeter {
+//!     fn greet(&self, name: &str) -> String;
+//! }
+//!
+//! struct English;
+//!
+//! impl Greeter for English {
+//!     fn greet(&self, name: &str) -> String {
+//!         format!("Hello {}", name)
+//!     }
+//! }
+//!
+//! #[roopert(delegate(Greeter => greeter, std::fmt::Display => title))]
+//! struct Person {
+//!     greeter: English,
+//!     title: String,
+//! }
+//!
+//! # fn main() {
+//! let person = Person { greeter: English, title: "Sir".to_string() };
+//! assert_eq!(person.greet("Bob"), "Hello Bob");
+//! assert_eq!(person.to_string(), "Sir");
+//! # }
+//! ```
+//!
+//! ### object_trait
+//! A trait-level attribute for downcasting trait objects to the concrete types which implement the trait.
+//! The trait gains the `roopert::AsAny` and `roopert::Hierarchy` supertraits, so every type which implements the trait must use the `extends` attribute.
+//! The trait object (`dyn Trait`) gets `is::<T>()`, `downcast_ref::<T>()`, `downcast_mut::<T>()` and `downcast::<T>()` (for `Box<dyn Trait>`) methods,
+//! for any type `T` which implements the trait; `downcast` returns the unchanged box when the trait object is a different type.
+//! The shared base of the trait object can be reached without a downcast with the `base = Type` parameter,
+//! which adds the `roopert::Upcast<Type>` supertrait (implemented by the `extends` attribute for direct parents and by every type for itself)
+//! and `as_base()` and `as_base_mut()` methods to the trait object.
+//! Only direct parents implement `Upcast` automatically (a generic implementation for further ancestors would overlap with the one for every type itself),
+//! so a type which extends a child of the base must implement `Upcast<Type>` itself, by upcasting through its parent.
+//! The `parent = Trait` parameter makes the parent trait a supertrait and adds `as_dyn_parent()`, `as_dyn_parent_mut()` and `into_dyn_parent()` methods,
+//! which convert the trait object to a parent trait object (with trait upcasting, which needs Rust 1.86 or newer).
+//! The standard form `#[roopert(object_trait, ...)]` attribute macro is used on the trait.
+//!
+//! ```
+//! # use roopert::roopert;
+//! #[roopert(extends)]
+//! struct WidgetBase {
+//!     id: usize,
+//! }
+//!
+//! #[roopert(object_trait, base = WidgetBase)]
+//! trait Widget {
+//!     fn draw(&self) -> String;
+//! }
+//!
+//! #[roopert(extends, WidgetBase)]
+//! struct Button {
+//!     base: WidgetBase,
+//!     label: String,
+//! }
+//!
+//! impl Widget for Button {
+//!     fn draw(&self) -> String {
+//!         format!("[{}]", self.label)
+//!     }
+//! }
+//!
+//! #[roopert(extends, Button)]
+//! struct IconButton {
+//!     button: Button,
+//!     icon: char,
+//! }
+//!
+//! // extends a child of the base, so it upcasts through its parent
+//! impl roopert::Upcast<WidgetBase> for IconButton {
+//!     fn upcast(&self) -> &WidgetBase {
+//!         roopert::Upcast::<WidgetBase>::upcast(&self.button)
+//!     }
+//!
+//!     fn upcast_mut(&mut self) -> &mut WidgetBase {
+//!         roopert::Upcast::<WidgetBase>::upcast_mut(&mut self.button)
+//!     }
+//! }
+//!
+//! impl Widget for IconButton {
+//!     fn draw(&self) -> String {
+//!         format!("{} {}", self.icon, self.button.draw())
+//!     }
+//! }
+//!
+//! # fn main() {
+//! let icon_button = IconButton { button: Button { base: WidgetBase { id: 1 }, label: "save".to_string() }, icon: '*' };
+//! let widgets: Vec<Box<dyn Widget>> = vec![Box::new(Button { base: WidgetBase { id: 0 }, label: "ok".to_string() }), Box::new(icon_button)];
+//! assert_eq!(widgets[0].as_base().id, 0);
+//! assert_eq!(widgets[1].as_base().id, 1);
+//! assert_eq!(widgets[0].downcast_ref::<Button>().unwrap().label, "ok");
+//! let button: Box<Button> = widgets.into_iter().next().unwrap().downcast::<Button>().ok().unwrap();
+//! assert_eq!(button.id, 0);
+//! # }
+//! ```
+//!
+//! ### dispatch
+//! An enum-level attribute for implementing traits for a closed set of types without trait objects, by matching on the variants of the enum.
+//! Every variant must have exactly one unnamed field, and every method of the trait is forwarded to the field of the matching variant.
+//! Traits are supplied like for the `delegate` attribute: the standard library traits which `delegate` supports can be dispatched directly,
+//! and other traits must be declared with the `#[roopert(delegatable)]` attribute.
+//! Methods must take a `self` parameter, associated types must be the same for every variant and associated constants are not supported.
+//! The attribute also implements `From<Variant>` for the enum and `TryFrom<Enum>` for each variant type (except for bare type parameters),
+//! and adds `as_variant()`, `as_variant_mut()` and `is_variant()` methods, with the variant name in snake case.
+//! The standard form `#[roopert(dispatch(Trait, ...))]` attribute macro is used on the enum.
+//!
+//! ```
+//! # use roopert::roopert;
+//! use std::convert::TryFrom;
+//!
+//! #[roopert(delegatable)]
+//! trait Shape {
+//!     fn area(&self) -> f64;
+//! }
+//!
+//! struct Circle {
+//!     radius: f64,
+//! }
+//!
+//! impl Shape for Circle {
+//!     fn area(&self) -> f64 {
+//!         3.0 * self.radius * self.radius
+//!     }
+//! }
+//!
+//! struct Square {
+//!     side: f64,
+//! }
+//!
+//! impl Shape for Square {
+//!     fn area(&self) -> f64 {
+//!         self.side * self.side
+//!     }
+//! }
+//!
+//! #[roopert(dispatch(Shape))]
+//! enum AnyShape {
+//!     Circle(Circle),
+//!     Square(Square),
+//! }
+//!
+//! # fn main() {
+//! let shapes: Vec<AnyShape> = vec![Circle { radius: 1.0 }.into(), Square { side: 2.0 }.into()];
+//! assert_eq!(shapes.iter().map(|shape| shape.area()).sum::<f64>(), 7.0);
+//! assert!(shapes[1].is_square());
+//! assert_eq!(shapes[0].as_circle().unwrap().radius, 1.0);
+//! assert!(Circle::try_from(shapes.into_iter().last().unwrap()).is_err());
+//! # }
+//! ```
+//!
+//! ### reflect
+//! A struct-level attribute for inspecting and modifying the fields of a named struct at runtime, for generic tooling like inspectors and diff viewers.
+//! The attribute adds a `FIELDS` constant with the static metadata (`roopert::FieldInfo`) of every field: its name, type name, visibility,
+//! whether it is a parent declared by the `extends` attribute and whether it has a getter or setter generated by the `accessors` attribute.
+//! The attribute also implements `roopert::Reflect`, which can be used as a trait object, with `get_field(name)` to get a field as a `&dyn Any`
+//! and `set_field(name, value)` to set a field from a `Box<dyn Any>`.
+//! Fields are set through their setter (including its `pre` and `post` operations) when they have one, or assigned directly when they are public;
+//! other fields are read-only and `set_field` returns a `roopert::ReflectError`.
+//! Likewise, `get_field` only returns fields which are public or have a getter, and `None` for other fields.
+//! The reflect attribute must be placed above the `extends` and `accessors` attributes, which report an error otherwise, so that parents and accessors are included.
+//! Optionally, `parents = nested` or `parents = flatten` walks into the parents declared by the `extends` attribute, which must also use the reflect attribute.
+//! Nested parent fields are reached with paths like `base.visible`, and flattened parent fields are reached as if they were fields of the struct;
+//! `all_fields()` lists the fields of the struct followed by the fields of its parents, with their paths.
+//! The standard form `#[roopert(reflect)]` attribute macro is used on the struct.
+//!
+//! ```
+//! # use roopert::roopert;
+//! use roopert::Reflect;
+//!
+//! #[roopert(reflect)]
+//! #[roopert(extends)]
+//! #[roopert(accessors, get = all, set = all)]
+//! struct Settings {
+//!     #[roopert(set, post = self.saved = false)]
+//!     volume: u8,
+//!     #[roopert(skip)]
+//!     saved: bool,
+//! }
+//!
+//! #[roopert(reflect, parents = nested)]
+//! #[roopert(extends, Settings)]
+//! struct UserSettings {
+//!     base: Settings,
+//! }
+//!
+//! # fn main() {
+//! let mut settings = Settings { volume: 1, saved: true };
+//! assert_eq!(Settings::FIELDS[0].name, "volume");
+//! assert!(Settings::FIELDS[0].has_setter);
+//! settings.set_field("volume", Box::new(11u8)).unwrap();
+//! assert_eq!(settings.get_field("volume").unwrap().downcast_ref::<u8>(), Some(&11));
+//! assert!(settings.set_field("saved", Box::new(true)).is_err());
+//! assert!(settings.get_field("saved").is_none());
+//! assert!(!settings.saved);
+//!
+//! let mut user = UserSettings { base: settings };
+//! user.set_field("base.volume", Box::new(5u8)).unwrap();
+//! assert_eq!(user.volume, 5);
+//! assert_eq!(user.all_fields()[1].path, "base.volume");
+//! # }
+//! ```
+//!
+//! ### properties
+//! A struct-level attribute for getting and setting fields by name with strings, for configuration files, consoles and scripting.
+//! The attribute implements `roopert::Properties`, which can be used as a trait object, for every field with a getter or setter generated by the `accessors` attribute.
+//! `get_property(name)` formats the field with its `Display` implementation and `set_property(name, value)` parses the value with the field's `FromStr` implementation,
+//! then sets the field through its setter (including its `pre` and `post` operations).
+//! Unknown properties, read-only or write-only properties, types without `Display` or `FromStr` and values which cannot be parsed return a `roopert::PropertyError`.
+//! The properties attribute must be placed above the `accessors` attribute, which reports an error otherwise, so that accessors are included.
+//! The standard form `#[roopert(properties)]` attribute macro is used on the struct.
+//!
+//! ```
+//! # use roopert::roopert;
+//! use roopert::{Properties, PropertyError};
+//!
+//! #[roopert(properties)]
+//! #[roopert(accessors, get = all, set = Except(id))]
+//! struct Window {
+//!     #[roopert(set, post = self.dirty = true)]
+//!     width: u32,
+//!     title: String,
+//!     id: u64,
+//!     #[roopert(skip)]
+//!     dirty: bool,
+//! }
+//!
+//! # fn main() {
+//! let mut window = Window { width: 640, title: "roopert".to_string(), id: 1, dirty: false };
+//! assert_eq!(window.properties(), &["width", "title", "id"]);
+//! window.set_property("width", "800").unwrap();
+//! assert_eq!(window.get_property("width").unwrap(), "800");
+//! assert!(window.dirty);
+//! assert!(matches!(window.set_property("width", "wide"), Err(PropertyError::Parse { .. })));
+//! assert!(matches!(window.set_property("id", "2"), Err(PropertyError::ReadOnly { .. })));
+//! # }
+//! ```
+//!
+//! ### crate
+//! An item-level attribute for the path of the roopert crate in generated code, for crates which re-export roopert from their own facade crate or module (like serde's `crate` attribute).
+//! Generated code refers to roopert through `::roopert` by default, which does not exist when roopert is only a dependency of the facade crate.
+//! The path applies to every roopert attribute of the item, whether they are above or below the crate attribute.
+//! The path can also be supplied to a single attribute, before the attribute type, like `#[roopert(crate = "my_facade::roopert", extends, Parent)]`.
+//! The standard form `#[roopert(crate = "path")]` attribute macro is used on the item.
+//!
+//! ```
+//! mod facade {
+//!     pub mod roopert {
+//!         pub use ::roopert::*;
+//!     }
+//! }
+//!
+//! use facade::roopert::roopert;
+//!
+//! #[roopert(crate = "crate::facade::roopert")]
+//! #[roopert(builder)]
+//! #[roopert(extends)]
+//! struct Settings {
+//!     volume: u8,
+//! }
+//!
+//! #[roopert(crate = "crate::facade::roopert", extends, Settings)]
+//! struct UserSettings {
+//!     base: Settings,
+//! }
+//!
+//! # fn main() {
+//! let settings = Settings::builder().volume(11).build().unwrap();
+//! let user = UserSettings { base: settings };
+//! assert_eq!(user.volume, 11);
+//! # }
+//! ```
+//!
+//! ### serde
+//! The optional `serde` cargo feature integrates the `extends` and `accessors` attributes with serde's `Serialize` and `Deserialize` derive macros.
+//! In the `extends` attribute, `serde` after a parent type adds `#[serde(flatten)]` to the parent field, so the struct is (de)serialized as a single object with the fields of its parent.
+//! In the `accessors` attribute, `serde` deserializes the struct through the generated setters, so their `pre` and `post` operations also run when the struct is loaded.
+//! Fields with setters start from their `Default` value before their setter is called, and are set in declaration order after the fields without setters.
+//! The struct must also derive `Deserialize`, below the roopert attributes.
+//! A `pre` or `post` operation which panics (like a failed `assert!`) makes deserialization return an error with the panic message, so it can validate the loaded values.
+//! The panic is caught with the default `std` feature, so without it (or with `panic = "abort"`) a failing operation still panics inside `Deserialize`.
+//!
+//! ```
+//! # #[cfg(feature = "serde")]
+//! # mod example {
+//! use roopert::roopert;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[roopert(extends)]
+//! #[roopert(accessors, set = all, serde)]
+//! #[derive(Serialize, Deserialize)]
+//! struct Account {
+//!     #[roopert(set, pre = assert!(!x.is_empty(), "missing email"))]
+//!     email: String,
+//! }
+//!
+//! #[roopert(extends, Account, serde)]
+//! #[derive(Serialize, Deserialize)]
+//! struct Admin {
+//!     base: Account,
+//!     level: u8,
+//! }
+//!
+//! # pub fn main() {
+//! // serialized as {"email":"admin@example.com","level":1}
+//! let admin: Admin = serde_json::from_str(r#"{"email":"admin@example.com","level":1}"#).unwrap();
+//! assert_eq!(admin.email, "admin@example.com");
+//! # }
+//! # }
+//! # fn main() {
+//! # #[cfg(feature = "serde")]
+//! # example::main();
+//! # }
+//! ```
+//!
+//! ### no_std
+//! Roopert is `no_std`, and the code generated by its attributes only uses `core` and `alloc` paths, through the `roopert` crate.
+//! The default `std` feature implements `std::error::Error` for the errors of roopert, and enables the `alloc` feature.
+//! Without the `alloc` feature (`roopert = { version = "0.2", default-features = false, features = ["macros"] }`), the `reflect` and `properties` attributes,
+//! `TypeInfo::parents()`, `TypeInfo::ancestors()`, `AsAny::into_any()` and the `downcast()` and `into_dyn_parent()` methods of the `object_trait` attribute are not available.
+//!
+//! ### Rust version
+//! Roopert needs Rust 1.86 or newer (the `rust-version` of the crate), for the trait upcasting of the `as_dyn_parent()`, `as_dyn_parent_mut()` and `into_dyn_parent()` methods
+//! generated by the `parent = Trait` parameter of the `object_trait` attribute (the `#[diagnostic::on_unimplemented]` message of the `abstract` attribute needs Rust 1.78).
+//!
+//! ### roopert-expand
+//! The `roopert-expand` binary (in `members/expand`) prints the code generated by the roopert attributes of a Rust source file, formatted with `rustfmt` when it is installed.
+//! Run `cargo run -p roopert_expand -- path/to/file.rs` (or pass `-` to read the file from stdin) to debug the generated code without `cargo expand` or a nightly compiler.
+//! Item-level `#[roopert(...)]` attributes are expanded from top to bottom, including in inline modules, and `#[roopert::roopert(...)]` attributes are expanded too.
+//!
 
 #![warn(missing_docs)]
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+mod builder;
+mod hierarchy;
+mod object;
+#[cfg(feature = "alloc")]
+mod properties;
+mod reflect;
+
+#[doc(hidden)]
+pub mod __private;
+
+pub use builder::BuildError;
+pub use hierarchy::{Hierarchy, HierarchyExt, TypeInfo};
+pub use object::{AsAny, Upcast};
+#[cfg(feature = "alloc")]
+pub use properties::{Properties, PropertyError};
+pub use reflect::{FieldInfo, FieldVisibility};
+#[cfg(feature = "alloc")]
+pub use reflect::{Reflect, ReflectedField, ReflectError};
 
 #[cfg(feature = "roopert_macro_root")]
 pub use roopert_macro_root::*;
@@ -0,0 +1,57 @@
+//! Support for trait objects of roopert hierarchies.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+use core::any::Any;
+
+/// Conversion to `Any` trait objects, for downcasting trait objects declared with the `object_trait` attribute.
+/// This is implemented for every `'static` type.
+pub trait AsAny: Any {
+    /// Convert to a `&dyn Any`
+    fn as_any(&self) -> &dyn Any;
+
+    /// Convert to a `&mut dyn Any`
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Convert to a `Box<dyn Any>`
+    #[cfg(feature = "alloc")]
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    #[cfg(feature = "alloc")]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> {
+        self
+    }
+}
+
+/// Conversion to a parent type, for reaching the shared base of trait objects declared with `#[roopert(object_trait, base = ...)]`.
+/// This is implemented by the `extends` attribute for every direct parent type, and for every type to itself.
+#[diagnostic::on_unimplemented(
+    note = "`extends` only implements `Upcast` for direct parents, so a type which extends a child of `{P}` must implement `Upcast<{P}>` through its parent",
+)]
+pub trait Upcast<P: ?Sized> {
+    /// Convert to a reference to the parent
+    fn upcast(&self) -> &P;
+
+    /// Convert to a mutable reference to the parent
+    fn upcast_mut(&mut self) -> &mut P;
+}
+
+impl<T: ?Sized> Upcast<T> for T {
+    fn upcast(&self) -> &T {
+        self
+    }
+
+    fn upcast_mut(&mut self) -> &mut T {
+        self
+    }
+}
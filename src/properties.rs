@@ -0,0 +1,76 @@
+//! String-keyed property access for types which use the `properties` attribute.
+
+use alloc::string::String;
+use core::fmt::{Display, Formatter};
+
+/// Error returned by `Properties::get_property` and `Properties::set_property`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PropertyError {
+    /// The struct does not have a property with the name.
+    UnknownProperty {
+        /// Name of the struct
+        target: &'static str,
+        /// Name of the unknown property
+        property: String,
+    },
+    /// The property does not have a setter.
+    ReadOnly {
+        /// Name of the struct
+        target: &'static str,
+        /// Name of the property
+        property: &'static str,
+    },
+    /// The property does not have a getter.
+    WriteOnly {
+        /// Name of the struct
+        target: &'static str,
+        /// Name of the property
+        property: &'static str,
+    },
+    /// The property's type does not implement `FromStr` (to set it) or `Display` (to get it).
+    Unsupported {
+        /// Name of the struct
+        target: &'static str,
+        /// Name of the property
+        property: &'static str,
+    },
+    /// The value could not be parsed into the property's type.
+    Parse {
+        /// Name of the struct
+        target: &'static str,
+        /// Name of the property
+        property: &'static str,
+        /// The value which could not be parsed
+        value: String,
+        /// The parse error, as displayed by the `FromStr` error
+        message: String,
+    },
+}
+
+impl Display for PropertyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownProperty { target, property } => write!(f, "Cannot access {}: unknown property {}", target, property),
+            Self::ReadOnly { target, property } => write!(f, "Cannot set {}.{}: property is read-only", target, property),
+            Self::WriteOnly { target, property } => write!(f, "Cannot get {}.{}: property is write-only", target, property),
+            Self::Unsupported { target, property } => write!(f, "Cannot access {}.{}: property type cannot be converted to or from a string", target, property),
+            Self::Parse { target, property, value, message } => write!(f, "Cannot set {}.{} to {:?}: {}", target, property, value, message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PropertyError {}
+
+/// String-keyed access to the accessor-enabled fields of a struct.
+/// This is implemented by the `properties` attribute, and can be used as a trait object.
+pub trait Properties {
+    /// Names of every property of the struct, in declaration order
+    fn properties(&self) -> &'static [&'static str];
+
+    /// Returns the property with the name, formatted with `Display`
+    fn get_property(&self, name: &str) -> Result<String, PropertyError>;
+
+    /// Parses the value with `FromStr` and sets the property with the name through its setter
+    fn set_property(&mut self, name: &str, value: &str) -> Result<(), PropertyError>;
+}
@@ -0,0 +1,120 @@
+//! Runtime field reflection for types which use the `reflect` attribute.
+
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::any::Any;
+#[cfg(feature = "alloc")]
+use core::fmt::{Display, Formatter};
+
+/// Visibility of a reflected field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FieldVisibility {
+    /// `pub`
+    Public,
+    /// `pub(crate)`
+    Crate,
+    /// `pub(super)`, `pub(self)` or `pub(in path)`
+    Restricted,
+    /// No visibility modifier
+    Private,
+}
+
+/// Static metadata of a reflected field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FieldInfo {
+    /// Name of the field
+    pub name: &'static str,
+    /// Name of the field's type, as written in the struct
+    pub type_name: &'static str,
+    /// Visibility of the field
+    pub visibility: FieldVisibility,
+    /// Whether the field is a parent declared by the `extends` attribute
+    pub is_parent: bool,
+    /// Whether the field has a getter generated by the `accessors` attribute
+    pub has_getter: bool,
+    /// Whether the field has a setter generated by the `accessors` attribute
+    pub has_setter: bool,
+}
+
+impl FieldInfo {
+    /// Returns true if the field has a getter or a setter
+    pub fn has_accessors(&self) -> bool {
+        self.has_getter || self.has_setter
+    }
+}
+
+/// Metadata of a field reached through `Reflect::all_fields`, which may be a field of a parent.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReflectedField {
+    /// Path of the field for `Reflect::get_field` and `Reflect::set_field`, like `base.visible` for a nested parent field
+    pub path: String,
+    /// Static metadata of the field
+    pub info: FieldInfo,
+}
+
+/// Error returned by `Reflect::set_field`.
+#[cfg(feature = "alloc")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ReflectError {
+    /// The struct does not have a field with the name.
+    UnknownField {
+        /// Name of the reflected struct
+        target: &'static str,
+        /// Name of the unknown field
+        field: String,
+    },
+    /// The value does not have the type of the field.
+    WrongType {
+        /// Name of the reflected struct
+        target: &'static str,
+        /// Name of the field
+        field: &'static str,
+        /// Name of the field's type
+        expected: &'static str,
+    },
+    /// The field is not public and does not have a setter.
+    ReadOnly {
+        /// Name of the reflected struct
+        target: &'static str,
+        /// Name of the field
+        field: &'static str,
+    },
+}
+
+#[cfg(feature = "alloc")]
+impl Display for ReflectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::UnknownField { target, field } => write!(f, "Cannot reflect {}: unknown field {}", target, field),
+            Self::WrongType { target, field, expected } => write!(f, "Cannot set {}.{}: expected value of type {}", target, field, expected),
+            Self::ReadOnly { target, field } => write!(f, "Cannot set {}.{}: field is read-only", target, field),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReflectError {}
+
+/// Dynamic access to the fields of a struct.
+/// This is implemented by the `reflect` attribute, and can be used as a trait object.
+#[cfg(feature = "alloc")]
+pub trait Reflect {
+    /// Static metadata of every field of the struct, in declaration order
+    fn fields(&self) -> &'static [FieldInfo];
+
+    /// Metadata of every field of the struct followed by the fields of its parents, when the `reflect` attribute walks into parents
+    fn all_fields(&self) -> Vec<ReflectedField> {
+        self.fields().iter()
+            .map(|info| ReflectedField { path: info.name.to_string(), info: *info })
+            .collect()
+    }
+
+    /// Returns a reference to the field with the name or path, or `None` if there is no such field
+    fn get_field(&self, name: &str) -> Option<&dyn Any>;
+
+    /// Sets the field with the name or path, through its setter if it has one.
+    /// Fields which are not public and do not have a setter are read-only.
+    fn set_field(&mut self, name: &str, value: Box<dyn Any>) -> Result<(), ReflectError>;
+}
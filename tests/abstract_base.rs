@@ -0,0 +1,96 @@
+//! Abstract base class tests
+
+use roopert::roopert;
+
+struct RoopertShape {
+    name: String,
+}
+
+impl RoopertShape {
+    fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+#[roopert(abstract, RoopertShape)]
+trait RoopertShapeMethods {
+    fn area(&self) -> f64;
+
+    fn describe(&self) -> String {
+        format!("{} with area {}", self.as_ref().name, self.area())
+    }
+}
+
+#[roopert(extends, RoopertShape)]
+struct RoopertSquare {
+    shape: RoopertShape,
+    side: f64,
+}
+
+impl RoopertShapeMethods for RoopertSquare {
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+}
+
+#[roopert(extends, RoopertShape)]
+struct RoopertCircle {
+    shape: RoopertShape,
+    radius: f64,
+}
+
+impl RoopertShapeMethods for RoopertCircle {
+    fn area(&self) -> f64 {
+        3.0 * self.radius * self.radius
+    }
+
+    fn describe(&self) -> String {
+        format!("round {}", self.name())
+    }
+}
+
+struct RoopertContainer<T> {
+    items: Vec<T>,
+}
+
+#[roopert(abstract, RoopertContainer<T>)]
+trait RoopertContainerMethods<T> {
+    fn first(&self) -> Option<&T>;
+}
+
+#[roopert(extends, RoopertContainer<T>)]
+struct RoopertStack<T> {
+    container: RoopertContainer<T>,
+}
+
+impl<T> RoopertContainerMethods<T> for RoopertStack<T> {
+    fn first(&self) -> Option<&T> {
+        self.items.last()
+    }
+}
+
+#[test]
+fn abstract_methods_test() {
+    let square = RoopertSquare {
+        shape: RoopertShape { name: "square".to_string() },
+        side: 2.0,
+    };
+    let circle = RoopertCircle {
+        shape: RoopertShape { name: "circle".to_string() },
+        radius: 1.0,
+    };
+    assert_eq!(square.name(), "square");
+    assert_eq!(square.describe(), "square with area 4");
+    assert_eq!(circle.describe(), "round circle");
+    let shapes: [&dyn RoopertShapeMethods; 2] = [&square, &circle];
+    assert_eq!(shapes.iter().map(|shape| shape.area()).sum::<f64>(), 7.0);
+}
+
+#[test]
+fn generic_abstract_test() {
+    let stack = RoopertStack {
+        container: RoopertContainer { items: vec![1, 2, 3] },
+    };
+    assert_eq!(stack.first(), Some(&3));
+    assert_eq!(stack.items.len(), 3);
+}
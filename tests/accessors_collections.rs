@@ -0,0 +1,110 @@
+//! Collection-aware accessor behaviour tests
+
+use std::collections::{HashMap, BTreeSet};
+
+use roopert::roopert;
+
+#[roopert(accessors, collection = All)]
+#[derive(Default)]
+struct RoopertCollectionTest {
+    #[roopert(collection, pre = self.pre_change(), post = self.post_change())]
+    items: Vec<String>,
+    #[collection(singular = "score_of")]
+    scores: HashMap<String, u32>,
+    categories: BTreeSet<u8>,
+    #[roopert(skip)]
+    ignored: Vec<u8>,
+    changes_started: usize,
+    changes_finished: usize,
+}
+
+impl RoopertCollectionTest {
+    fn pre_change(&mut self) {
+        self.changes_started += 1;
+    }
+    
+    fn post_change(&mut self) {
+        self.changes_finished += 1;
+    }
+    
+    // would conflict with the generated collection accessor if skip was ignored
+    fn ignored(&self) -> &[u8] {
+        &self.ignored
+    }
+}
+
+#[test]
+fn vec_test() {
+    let mut var = RoopertCollectionTest::default();
+    var.push_item("a".into());
+    var.push_item("b".into());
+    assert_eq!(var.items(), &["a".to_string(), "b".to_string()]);
+    assert_eq!(var.items_iter().count(), 2);
+    assert_eq!(var.remove_item(0), "a");
+    var.clear_items();
+    assert!(var.items().is_empty());
+    assert_eq!(var.changes_started, 4);
+    assert_eq!(var.changes_finished, 4);
+    assert!(var.ignored().is_empty());
+}
+
+#[test]
+fn map_test() {
+    let mut var = RoopertCollectionTest::default();
+    assert_eq!(var.insert_score_of("a".into(), 1), None);
+    assert_eq!(var.insert_score_of("a".into(), 2), Some(1));
+    assert_eq!(var.get_score_of(&"a".into()), Some(&2));
+    assert_eq!(var.scores_iter().count(), 1);
+    assert_eq!(var.remove_score_of(&"a".into()), Some(2));
+    var.clear_scores();
+    assert!(var.scores().is_empty());
+}
+
+#[test]
+fn set_test() {
+    let mut var = RoopertCollectionTest::default();
+    assert!(var.insert_category(1));
+    assert!(!var.insert_category(1));
+    assert!(var.contains_category(&1));
+    assert_eq!(var.categories_iter().copied().collect::<Vec<_>>(), vec![1]);
+    assert!(var.remove_category(&1));
+    var.clear_categories();
+    assert!(var.categories().is_empty());
+}
+
+#[roopert(accessors, collection = All)]
+#[derive(Default)]
+struct RoopertPluralTest {
+    statuses: Vec<u8>,
+    addresses: Vec<String>,
+    boxes: Vec<u8>,
+    matches: Vec<u8>,
+    wishes: Vec<u8>,
+    cases: Vec<u8>,
+    caches: Vec<u8>,
+    movies: Vec<u8>,
+    entries: Vec<u8>,
+}
+
+#[test]
+fn es_plural_test() {
+    let mut var = RoopertPluralTest::default();
+    var.push_status(1);
+    var.push_address("home".into());
+    var.push_box(2);
+    var.push_match(3);
+    var.push_wish(4);
+    var.push_case(5);
+    var.push_cache(6);
+    var.push_movie(7);
+    var.push_entry(8);
+    assert_eq!(var.statuses(), &[1]);
+    assert_eq!(var.addresses(), &["home".to_string()]);
+    assert_eq!(var.boxes(), &[2]);
+    assert_eq!(var.matches(), &[3]);
+    assert_eq!(var.wishes(), &[4]);
+    assert_eq!(var.cases(), &[5]);
+    assert_eq!(var.caches(), &[6]);
+    assert_eq!(var.movies(), &[7]);
+    assert_eq!(var.entries(), &[8]);
+}
@@ -1,4 +1,5 @@
 //! Accessor pre- and post-operation behaviour tests
+#![allow(clippy::bool_assert_comparison)]
 
 use roopert::roopert;
 
@@ -44,3 +45,34 @@ fn set_test() {
     assert_eq!(var.is_foo_preset, true);
     assert_eq!(var.is_foo_postset, true);
 }
+
+#[roopert(accessors)]
+#[derive(Default)]
+struct RoopertSetterVariantTest {
+    #[roopert(set, replace, take, update, pre = self.pre_bar_set(), post = self.post_bar_set())]
+    bar: Vec<u8>,
+    bar_presets: usize,
+    bar_postsets: usize,
+}
+
+impl RoopertSetterVariantTest {
+    fn pre_bar_set(&mut self) {
+        self.bar_presets += 1;
+    }
+    
+    fn post_bar_set(&mut self) {
+        self.bar_postsets += 1;
+    }
+}
+
+#[test]
+fn set_variants_test() {
+    let mut var = RoopertSetterVariantTest::default();
+    var.set_bar(vec![1]);
+    assert_eq!(var.replace_bar(vec![2]), vec![1]);
+    var.update_bar(|bar| bar.push(3));
+    assert_eq!(var.take_bar(), vec![2, 3]);
+    assert!(var.bar.is_empty());
+    assert_eq!(var.bar_presets, 4);
+    assert_eq!(var.bar_postsets, 4);
+}
@@ -0,0 +1,83 @@
+//! Accessor auto-rule and skip behaviour tests
+
+use roopert::roopert;
+
+#[roopert(accessors, get = All, set = Except(secret, token))]
+#[derive(Default)]
+struct RoopertExceptTest {
+    name: String,
+    secret: String,
+    token: u64,
+}
+
+// these would conflict with generated setters if the Except rule did not exclude the fields
+#[allow(dead_code)]
+impl RoopertExceptTest {
+    fn set_secret(&mut self, _x: String) {}
+    fn set_token(&mut self, _x: u64) {}
+}
+
+#[roopert(accessors, get = All, set = All)]
+#[derive(Default)]
+struct RoopertSkipTest {
+    visible: String,
+    #[roopert(skip)]
+    hidden: String,
+    #[get(skip)]
+    write_only: u32,
+    #[roopert(set, skip)]
+    read_only: u32,
+}
+
+// these would conflict with generated accessors if skip did not suppress them
+#[allow(dead_code)]
+impl RoopertSkipTest {
+    fn get_hidden(&self) -> &str { &self.hidden }
+    fn set_hidden(&mut self, _x: String) {}
+    fn get_write_only(&self) -> u32 { 0 }
+    fn set_read_only(&mut self, _x: u32) {}
+}
+
+#[roopert(accessors, get = PubCrate, set = Private)]
+#[derive(Default)]
+pub struct RoopertVisibilityTest {
+    private: u8,
+    pub(crate) crate_visible: u8,
+    pub public: u8,
+}
+
+// would conflict with the generated getter if PubCrate included public fields
+#[allow(dead_code)]
+impl RoopertVisibilityTest {
+    fn get_public(&self) -> u8 { self.public }
+    fn set_crate_visible(&mut self, _x: u8) {}
+}
+
+#[test]
+fn except_test() {
+    let mut var = RoopertExceptTest::default();
+    var.set_name("name".into());
+    assert_eq!(var.get_name(), "name");
+    assert_eq!(var.get_secret(), "");
+    assert_eq!(*var.get_token(), 0);
+}
+
+#[test]
+fn skip_test() {
+    let mut var = RoopertSkipTest::default();
+    var.set_visible("visible".into());
+    assert_eq!(var.get_visible(), "visible");
+    var.set_write_only(42);
+    assert_eq!(var.write_only, 42);
+    assert_eq!(*var.get_read_only(), 0);
+    assert_eq!(var.get_hidden(), "");
+}
+
+#[test]
+fn visibility_test() {
+    let mut var = RoopertVisibilityTest::default();
+    var.set_private(1);
+    assert_eq!(*var.get_private(), 1);
+    assert_eq!(*var.get_crate_visible(), 0);
+    assert_eq!(var.get_public(), 0);
+}
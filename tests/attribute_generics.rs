@@ -1,5 +1,6 @@
 //! System test for roopert attributes on a generic struct
 //! A lot of important pieces of this are compile-time tests, but runtime tests are also included for completeness.
+#![allow(clippy::redundant_field_names, clippy::disallowed_names)]
 use roopert::roopert;
 //use core::convert::AsRef;
 
@@ -0,0 +1,119 @@
+//! Builder generation tests
+
+use roopert::{roopert, BuildError};
+
+#[roopert(builder)]
+#[roopert(extends, String)]
+#[derive(Debug)]
+struct RoopertBuilderTest {
+    #[roopert(parent)]
+    name: String,
+    size: usize,
+    #[builder(default)]
+    tags: Vec<String>,
+    #[roopert(builder, default = 42)]
+    answer: u32,
+}
+
+#[roopert(builder, name = "GenericBuilder", into)]
+struct RoopertGenericBuilderTest<T: Clone> {
+    label: String,
+    value: T,
+}
+
+#[test]
+fn build_test() {
+    let var = RoopertBuilderTest::builder()
+        .name("foo".into())
+        .size(3)
+        .build()
+        .unwrap();
+    assert_eq!(var.as_str(), "foo");
+    assert_eq!(var.size, 3);
+    assert!(var.tags.is_empty());
+    assert_eq!(var.answer, 42);
+}
+
+#[test]
+fn missing_field_test() {
+    let err = RoopertBuilderTest::builder()
+        .size(3)
+        .build()
+        .unwrap_err();
+    assert_eq!(err, BuildError::MissingField { target: "RoopertBuilderTest", field: "name" });
+}
+
+#[test]
+fn parent_test() {
+    let var = RoopertBuilderTestBuilder::from("bar".to_string())
+        .size(1)
+        .answer(7)
+        .build()
+        .unwrap();
+    assert_eq!(var.as_str(), "bar");
+    assert_eq!(var.answer, 7);
+}
+
+#[test]
+fn generic_test() {
+    let var = GenericBuilder::<u32>::new()
+        .label("baz")
+        .value(5u8)
+        .build()
+        .unwrap();
+    assert_eq!(var.label, "baz");
+    assert_eq!(var.value, 5);
+}
+
+#[roopert(builder, typestate)]
+#[roopert(extends, String)]
+struct RoopertTypestateTest<'a, T: Clone> {
+    name: String,
+    reference: &'a T,
+    #[builder(default)]
+    count: usize,
+    #[builder(into)]
+    description: String,
+}
+
+#[test]
+fn typestate_test() {
+    let value = 5u8;
+    let var = RoopertTypestateTest::builder()
+        .description("described")
+        .reference(&value)
+        .name("foo".into())
+        .count(2)
+        .build();
+    assert_eq!(var.as_str(), "foo");
+    assert_eq!(*var.reference, 5);
+    assert_eq!(var.count, 2);
+    assert_eq!(var.description, "described");
+}
+
+#[test]
+fn typestate_parent_test() {
+    let value = 1u8;
+    let var = RoopertTypestateTestBuilder::from("bar".to_string())
+        .reference(&value)
+        .description("")
+        .build();
+    assert_eq!(var.as_str(), "bar");
+    assert_eq!(var.count, 0);
+}
+
+#[roopert(builder, typestate)]
+struct RoopertRawFieldTest {
+    r#type: String,
+    r#ref: u8,
+}
+
+#[test]
+fn typestate_raw_field_test() {
+    let var = RoopertRawFieldTest::builder()
+        .r#type("kind".to_string())
+        .r#ref(1)
+        .build();
+    assert_eq!(var.r#type, "kind");
+    assert_eq!(var.r#ref, 1);
+}
@@ -0,0 +1,40 @@
+//! Constructor generation tests
+
+use roopert::roopert;
+
+#[roopert(new)]
+#[roopert(extend, String)]
+struct RoopertNewTest {
+    #[roopert(parent)]
+    name: String,
+    count: usize,
+    #[new(default)]
+    history: Vec<String>,
+    #[roopert(new, value = count * 2)]
+    double: usize,
+}
+
+#[roopert(new, name = "create", vis = "pub(crate)", into)]
+struct RoopertNewIntoTest<T: Clone> {
+    label: String,
+    value: T,
+    #[new(value = value.clone())]
+    initial: T,
+}
+
+#[test]
+fn new_test() {
+    let var = RoopertNewTest::new("foo".into(), 2);
+    assert_eq!(var.as_str(), "foo");
+    assert_eq!(var.count, 2);
+    assert!(var.history.is_empty());
+    assert_eq!(var.double, 4);
+}
+
+#[test]
+fn new_into_test() {
+    let var = RoopertNewIntoTest::<u32>::create("bar", 3u8);
+    assert_eq!(var.label, "bar");
+    assert_eq!(var.value, 3);
+    assert_eq!(var.initial, 3);
+}
@@ -0,0 +1,179 @@
+//! Trait delegation tests
+
+use roopert::roopert;
+
+mod traits {
+    use roopert::roopert;
+
+    #[roopert(delegatable)]
+    pub trait RoopertCounter {
+        type Count;
+
+        const STEP: usize;
+
+        fn count(&self) -> Self::Count;
+
+        fn increment(&mut self, by: usize);
+
+        fn describe<T: core::fmt::Display>(&self, prefix: T) -> String;
+
+        fn into_count(self) -> Self::Count;
+
+        fn name() -> &'static str;
+    }
+
+    #[roopert(delegatable)]
+    pub trait RoopertConvert<T> {
+        fn convert(&self, x: T) -> T;
+    }
+
+    // named like a standard library trait, but delegated with its own definition
+    #[roopert(delegatable)]
+    pub trait Display {
+        fn display(&self) -> String;
+    }
+}
+
+use traits::{RoopertCounter, RoopertConvert, Display};
+
+#[derive(Default)]
+struct RoopertSimpleCounter {
+    total: usize,
+}
+
+impl RoopertCounter for RoopertSimpleCounter {
+    type Count = usize;
+
+    const STEP: usize = 2;
+
+    fn count(&self) -> usize {
+        self.total
+    }
+
+    fn increment(&mut self, by: usize) {
+        self.total += by * Self::STEP;
+    }
+
+    fn describe<T: core::fmt::Display>(&self, prefix: T) -> String {
+        format!("{}{}", prefix, self.total)
+    }
+
+    fn into_count(self) -> usize {
+        self.total
+    }
+
+    fn name() -> &'static str {
+        "simple"
+    }
+}
+
+impl RoopertConvert<u32> for RoopertSimpleCounter {
+    fn convert(&self, x: u32) -> u32 {
+        x + self.total as u32
+    }
+}
+
+impl RoopertConvert<String> for RoopertSimpleCounter {
+    fn convert(&self, x: String) -> String {
+        format!("{}{}", x, self.total)
+    }
+}
+
+#[roopert(delegate(RoopertCounter => counter, RoopertConvert<u32> => counter))]
+#[roopert(delegate, core::fmt::Display => label)]
+struct RoopertDelegator {
+    counter: RoopertSimpleCounter,
+    label: String,
+}
+
+#[roopert(delegate(RoopertConvert => inner, core::iter::Iterator => items, std::fmt::Debug => items))]
+struct RoopertGenericDelegator<I: Iterator<Item = u8>> {
+    inner: RoopertSimpleCounter,
+    items: I,
+}
+
+#[roopert(delegate(std::io::Write => buffer, std::fmt::Write => text))]
+#[derive(Default)]
+struct RoopertWriter {
+    buffer: Vec<u8>,
+    text: String,
+}
+
+#[roopert(delegate(core::fmt::Write => text))]
+#[derive(Default)]
+struct RoopertFmtWriter {
+    text: String,
+}
+
+impl Display for RoopertSimpleCounter {
+    fn display(&self) -> String {
+        format!("counter {}", self.total)
+    }
+}
+
+#[roopert(delegate(Display => counter, std::fmt::Display => label))]
+struct RoopertDisplayDelegator {
+    counter: RoopertSimpleCounter,
+    label: String,
+}
+
+#[test]
+fn delegate_test() {
+    let mut delegator = RoopertDelegator {
+        counter: RoopertSimpleCounter::default(),
+        label: "delegated".to_string(),
+    };
+    delegator.increment(3);
+    assert_eq!(delegator.count(), 6);
+    assert_eq!(delegator.describe("count: "), "count: 6");
+    assert_eq!(<RoopertDelegator as RoopertCounter>::STEP, 2);
+    assert_eq!(<RoopertDelegator as RoopertCounter>::name(), "simple");
+    assert_eq!(delegator.convert(1u32), 7);
+    assert_eq!(delegator.to_string(), "delegated");
+    let count: <RoopertDelegator as RoopertCounter>::Count = delegator.into_count();
+    assert_eq!(count, 6);
+}
+
+#[test]
+fn generic_delegate_test() {
+    let mut delegator = RoopertGenericDelegator {
+        inner: RoopertSimpleCounter { total: 1 },
+        items: vec![1u8, 2, 3].into_iter(),
+    };
+    assert_eq!(delegator.convert(1u32), 2);
+    assert_eq!(delegator.convert("total ".to_string()), "total 1");
+    assert_eq!(delegator.next(), Some(1));
+    assert_eq!(delegator.size_hint(), (2, Some(2)));
+    assert_eq!(format!("{:?}", delegator), format!("{:?}", vec![2u8, 3].into_iter()));
+    assert_eq!(delegator.sum::<u8>(), 5);
+}
+
+#[test]
+fn builtin_delegate_test() {
+    use std::io::Write as _;
+    let mut writer = RoopertWriter::default();
+    writer.write_all(b"bytes").unwrap();
+    writer.flush().unwrap();
+    std::fmt::Write::write_fmt(&mut writer, format_args!("text {}", 1)).unwrap();
+    assert_eq!(writer.buffer, b"bytes");
+    assert_eq!(writer.text, "text 1");
+}
+
+#[test]
+fn fmt_write_delegate_test() {
+    use core::fmt::Write;
+    let mut writer = RoopertFmtWriter::default();
+    write!(writer, "text {}", 2).unwrap();
+    writer.write_char('!').unwrap();
+    assert_eq!(writer.text, "text 2!");
+}
+
+#[test]
+fn std_named_delegate_test() {
+    let delegator = RoopertDisplayDelegator {
+        counter: RoopertSimpleCounter { total: 4 },
+        label: "label".to_string(),
+    };
+    assert_eq!(Display::display(&delegator), "counter 4");
+    assert_eq!(delegator.to_string(), "label");
+}
@@ -0,0 +1,135 @@
+//! Enum dispatch tests
+
+use std::convert::TryFrom;
+
+use roopert::roopert;
+
+mod traits {
+    use roopert::roopert;
+
+    #[roopert(delegatable)]
+    pub trait RoopertShape {
+        type Unit;
+
+        fn area(&self) -> f64;
+
+        fn scale(&mut self, by: f64);
+
+        fn describe<T: core::fmt::Display>(&self, prefix: T) -> String;
+
+        fn into_unit(self) -> Self::Unit;
+    }
+}
+
+use traits::RoopertShape;
+
+#[derive(Debug, PartialEq)]
+struct RoopertCircle {
+    radius: f64,
+}
+
+impl RoopertShape for RoopertCircle {
+    type Unit = &'static str;
+
+    fn area(&self) -> f64 {
+        3.0 * self.radius * self.radius
+    }
+
+    fn scale(&mut self, by: f64) {
+        self.radius *= by;
+    }
+
+    fn describe<T: core::fmt::Display>(&self, prefix: T) -> String {
+        format!("{}circle {}", prefix, self.radius)
+    }
+
+    fn into_unit(self) -> &'static str {
+        "cm"
+    }
+}
+
+impl std::fmt::Display for RoopertCircle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({})", self.radius)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct RoopertSquare {
+    side: f64,
+}
+
+impl RoopertShape for RoopertSquare {
+    type Unit = &'static str;
+
+    fn area(&self) -> f64 {
+        self.side * self.side
+    }
+
+    fn scale(&mut self, by: f64) {
+        self.side *= by;
+    }
+
+    fn describe<T: core::fmt::Display>(&self, prefix: T) -> String {
+        format!("{}square {}", prefix, self.side)
+    }
+
+    fn into_unit(self) -> &'static str {
+        "m"
+    }
+}
+
+impl std::fmt::Display for RoopertSquare {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}]", self.side)
+    }
+}
+
+#[roopert(dispatch(RoopertShape, std::fmt::Display))]
+#[derive(Debug, PartialEq)]
+enum RoopertAnyShape {
+    Circle(RoopertCircle),
+    BigSquare(RoopertSquare),
+}
+
+#[roopert(dispatch, core::iter::Iterator)]
+enum RoopertNumbers<I: Iterator<Item = u8>> {
+    Range(std::ops::Range<u8>),
+    Other(I),
+}
+
+#[test]
+fn dispatch_test() {
+    let mut shapes: Vec<RoopertAnyShape> = vec![RoopertCircle { radius: 1.0 }.into(), RoopertSquare { side: 2.0 }.into()];
+    assert_eq!(shapes.iter().map(|shape| shape.area()).sum::<f64>(), 7.0);
+    for shape in shapes.iter_mut() {
+        shape.scale(2.0);
+    }
+    assert_eq!(shapes[0].describe("a "), "a circle 2");
+    assert_eq!(shapes[1].to_string(), "[4]");
+    let square = shapes.pop().unwrap();
+    let unit: <RoopertAnyShape as RoopertShape>::Unit = square.into_unit();
+    assert_eq!(unit, "m");
+}
+
+#[test]
+fn conversion_test() {
+    let mut shape = RoopertAnyShape::from(RoopertCircle { radius: 1.0 });
+    assert!(shape.is_circle());
+    assert!(!shape.is_big_square());
+    assert_eq!(shape.as_circle(), Some(&RoopertCircle { radius: 1.0 }));
+    assert_eq!(shape.as_big_square(), None);
+    shape.as_circle_mut().unwrap().radius = 3.0;
+    assert!(shape.as_big_square_mut().is_none());
+    let shape = RoopertSquare::try_from(shape).unwrap_err();
+    assert_eq!(RoopertCircle::try_from(shape).unwrap(), RoopertCircle { radius: 3.0 });
+}
+
+#[test]
+fn generic_dispatch_test() {
+    let range: RoopertNumbers<std::vec::IntoIter<u8>> = RoopertNumbers::Range(1..4);
+    assert_eq!(range.size_hint(), (3, Some(3)));
+    assert_eq!(range.sum::<u8>(), 6);
+    let other: RoopertNumbers<std::vec::IntoIter<u8>> = RoopertNumbers::Other(vec![5, 6].into_iter());
+    assert_eq!(other.collect::<Vec<u8>>(), vec![5, 6]);
+}
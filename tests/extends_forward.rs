@@ -0,0 +1,62 @@
+//! Forwarded parent trait tests
+
+use std::collections::HashSet;
+use std::fmt::Write as _;
+
+use roopert::roopert;
+
+#[roopert(extends, String, forward(Display, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, fmt::Write))]
+struct RoopertName {
+    name: String,
+    lookups: usize,
+}
+
+#[roopert(extends, Vec<T>, forward(IntoIterator, Index, IndexMut, Extend, Debug))]
+struct RoopertList<T> {
+    items: Vec<T>,
+}
+
+#[roopert(extends, Vec<u8>, forward(io::Write, Debug))]
+struct RoopertBuffer {
+    bytes: Vec<u8>,
+}
+
+#[test]
+fn forward_string_test() {
+    let mut name = RoopertName { name: "roopert".to_string(), lookups: 0 };
+    let other = RoopertName { name: "roopert".to_string(), lookups: 1 };
+    assert_eq!(name.to_string(), "roopert");
+    assert_eq!(format!("{:?}", name), "\"roopert\"");
+    assert!(name == other);
+    assert!(name <= other);
+    let mut set = HashSet::new();
+    set.insert(other);
+    assert!(set.contains(&name));
+    write!(name, "-rs").unwrap();
+    assert_eq!(name.name, "roopert-rs");
+    assert_eq!(name.lookups, 0);
+}
+
+#[test]
+fn forward_vec_test() {
+    let mut list = RoopertList { items: vec![1, 2] };
+    list.extend(vec![3, 4]);
+    list[0] = 10;
+    assert_eq!(list[0], 10);
+    assert_eq!((&list).into_iter().sum::<i32>(), 19);
+    for item in &mut list {
+        *item += 1;
+    }
+    assert_eq!(format!("{:?}", list), "[11, 3, 4, 5]");
+    assert_eq!(list.into_iter().collect::<Vec<_>>(), vec![11, 3, 4, 5]);
+}
+
+#[test]
+fn forward_io_test() {
+    use std::io::Write;
+    let mut buffer = RoopertBuffer { bytes: Vec::new() };
+    buffer.write_all(b"abc").unwrap();
+    buffer.flush().unwrap();
+    assert_eq!(buffer.bytes, b"abc");
+    assert_eq!(format!("{:?}", buffer), "[97, 98, 99]");
+}
@@ -0,0 +1,104 @@
+//! Parent operator tests
+
+use std::num::Wrapping;
+
+use roopert::roopert;
+
+#[roopert(extends, f64, ops, forward(PartialEq, PartialOrd, Debug))]
+#[derive(Clone, Copy)]
+struct RoopertMeters {
+    meters: f64,
+}
+
+#[roopert(extends, u32, ops)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct RoopertCount {
+    count: u32,
+}
+
+#[roopert(extends, u32, ops(Add, AddAssign, BitAnd, Shl, Not))]
+#[derive(Debug, Clone, PartialEq)]
+struct RoopertFlags {
+    bits: u32,
+    name: &'static str,
+}
+
+#[roopert(extends, i64, ops(Add, Neg, rest = default))]
+#[derive(Debug, PartialEq)]
+struct RoopertScore {
+    score: i64,
+    history: Vec<i64>,
+}
+
+#[roopert(extends, Wrapping<T>, ops(Add, SubAssign))]
+#[derive(Debug, PartialEq)]
+struct RoopertWrapper<T> {
+    value: Wrapping<T>,
+}
+
+#[test]
+fn default_ops_test() {
+    let a = RoopertMeters { meters: 3.0 };
+    let b = RoopertMeters { meters: 1.5 };
+    assert_eq!(a + b, RoopertMeters { meters: 4.5 });
+    assert_eq!(a - b, RoopertMeters { meters: 1.5 });
+    assert_eq!(a * 2.0, RoopertMeters { meters: 6.0 });
+    assert_eq!(a / b, RoopertMeters { meters: 2.0 });
+    assert_eq!(a % 2.0, RoopertMeters { meters: 1.0 });
+    assert!(b < a);
+    let mut c = a;
+    c += b;
+    c -= 0.5;
+    c *= 2.0;
+    c /= RoopertMeters { meters: 2.0 };
+    c %= 3.0;
+    assert_eq!(c, RoopertMeters { meters: 1.0 });
+}
+
+#[test]
+fn default_ops_unsigned_test() {
+    let a = RoopertCount { count: 7 };
+    let b = RoopertCount { count: 2 };
+    assert_eq!(a + b, RoopertCount { count: 9 });
+    assert_eq!(a - b, RoopertCount { count: 5 });
+    assert_eq!(a * 3, RoopertCount { count: 21 });
+    assert_eq!(a / b, RoopertCount { count: 3 });
+    assert_eq!(a % 4, RoopertCount { count: 3 });
+    let mut c = a;
+    c += b;
+    c -= 1;
+    c *= b;
+    c /= 4;
+    c %= RoopertCount { count: 3 };
+    assert_eq!(c, RoopertCount { count: 1 });
+}
+
+#[test]
+fn selected_ops_lhs_test() {
+    let a = RoopertFlags { bits: 0b0110, name: "a" };
+    let b = RoopertFlags { bits: 0b0011, name: "b" };
+    assert_eq!(a.clone() & b.clone(), RoopertFlags { bits: 0b0010, name: "a" });
+    assert_eq!(b.clone() + a.clone(), RoopertFlags { bits: 0b1001, name: "b" });
+    assert_eq!(a.clone() << 1, RoopertFlags { bits: 0b1100, name: "a" });
+    assert_eq!((!a.clone()).bits, !0b0110);
+    let mut c = a;
+    c += b;
+    assert_eq!(c, RoopertFlags { bits: 0b1001, name: "a" });
+}
+
+#[test]
+fn rest_default_test() {
+    let a = RoopertScore { score: 3, history: vec![1, 2] };
+    let b = RoopertScore { score: 4, history: vec![3] };
+    assert_eq!(a + b, RoopertScore { score: 7, history: Vec::new() });
+    assert_eq!(-RoopertScore { score: 1, history: vec![1] }, RoopertScore { score: -1, history: Vec::new() });
+}
+
+#[test]
+fn generic_ops_test() {
+    let a = RoopertWrapper { value: Wrapping(255u8) };
+    assert_eq!(a + RoopertWrapper { value: Wrapping(2) }, RoopertWrapper { value: Wrapping(1) });
+    let mut b = RoopertWrapper { value: Wrapping(5i32) };
+    b -= Wrapping(2);
+    assert_eq!(b.value, Wrapping(3));
+}
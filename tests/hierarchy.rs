@@ -0,0 +1,81 @@
+//! Runtime type information tests
+
+use roopert::{roopert, Hierarchy, HierarchyExt};
+
+#[roopert(extends)]
+struct RoopertAnimal {
+    legs: usize,
+}
+
+#[roopert(extends, RoopertAnimal)]
+struct RoopertDog {
+    animal: RoopertAnimal,
+}
+
+#[roopert(extends, RoopertDog)]
+struct RoopertPuppy {
+    dog: RoopertDog,
+}
+
+#[roopert(extends, String)]
+struct RoopertName {
+    name: String,
+}
+
+#[roopert(extends, Vec<T>)]
+struct RoopertStack<T: 'static> {
+    items: Vec<T>,
+}
+
+#[test]
+fn is_a_test() {
+    let puppy = RoopertPuppy {
+        dog: RoopertDog { animal: RoopertAnimal { legs: 4 } },
+    };
+    let name = RoopertName { name: "spot".to_string() };
+    assert!(puppy.is_a::<RoopertPuppy>());
+    assert!(puppy.is_a::<RoopertDog>());
+    assert!(puppy.is_a::<RoopertAnimal>());
+    assert!(!puppy.is_a::<u32>());
+    assert!(name.is_a::<String>());
+    assert!(!name.is_a::<RoopertAnimal>());
+    assert!(puppy.dog.is_a::<RoopertAnimal>());
+    assert!(!puppy.dog.is_a::<RoopertPuppy>());
+    assert!(!puppy.dog.animal.is_a::<RoopertDog>());
+    assert_eq!(puppy.legs, 4);
+    assert_eq!(name.as_str(), "spot");
+}
+
+#[test]
+fn trait_object_test() {
+    let animals: Vec<Box<dyn Hierarchy>> = vec![
+        Box::new(RoopertAnimal { legs: 2 }),
+        Box::new(RoopertDog { animal: RoopertAnimal { legs: 4 } }),
+        Box::new(RoopertStack { items: vec![1u8] }),
+    ];
+    let dogs: Vec<&str> = animals.iter()
+        .filter(|animal| animal.is_a::<RoopertDog>())
+        .map(|animal| animal.type_name())
+        .collect();
+    assert_eq!(dogs, vec![std::any::type_name::<RoopertDog>()]);
+    assert!(animals.iter().all(|animal| !animal.is_a::<RoopertPuppy>()));
+    assert!(animals[2].is_a::<Vec<u8>>());
+    assert!(!animals[2].is_a::<Vec<u16>>());
+}
+
+#[test]
+fn ancestors_test() {
+    let info = RoopertPuppy::static_type_info();
+    assert_eq!(info.name(), std::any::type_name::<RoopertPuppy>());
+    assert_eq!(info.type_id(), std::any::TypeId::of::<RoopertPuppy>());
+    let parents: Vec<&str> = info.parents().iter().map(|parent| parent.name()).collect();
+    assert_eq!(parents, vec![std::any::type_name::<RoopertDog>()]);
+    let ancestors: Vec<&str> = info.ancestors().iter().map(|ancestor| ancestor.name()).collect();
+    assert_eq!(ancestors, vec![std::any::type_name::<RoopertDog>(), std::any::type_name::<RoopertAnimal>()]);
+    let name_parents = RoopertName::static_type_info().parents();
+    assert_eq!(name_parents.len(), 1);
+    assert!(name_parents[0].is_a::<String>());
+    assert!(name_parents[0].parents().is_empty());
+    assert!(RoopertAnimal::static_type_info().ancestors().is_empty());
+    assert_eq!(info, RoopertPuppy::static_type_info());
+}
@@ -0,0 +1,139 @@
+//! Trait object downcasting tests
+
+use roopert::roopert;
+
+#[roopert(extends)]
+#[derive(Debug, PartialEq)]
+struct RoopertWidgetBase {
+    id: usize,
+}
+
+#[roopert(object_trait)]
+trait RoopertWidget {
+    fn draw(&self) -> String;
+}
+
+#[roopert(extends, RoopertWidgetBase)]
+#[derive(Debug, PartialEq)]
+struct RoopertButton {
+    base: RoopertWidgetBase,
+    label: String,
+}
+
+impl RoopertWidget for RoopertButton {
+    fn draw(&self) -> String {
+        format!("[{}]", self.label)
+    }
+}
+
+#[roopert(extends, RoopertWidgetBase)]
+#[derive(Debug, PartialEq)]
+struct RoopertSlider {
+    base: RoopertWidgetBase,
+    value: u8,
+}
+
+impl RoopertWidget for RoopertSlider {
+    fn draw(&self) -> String {
+        format!("<{}>", self.value)
+    }
+}
+
+#[roopert(object_trait)]
+pub trait RoopertGenericWidget<T> {
+    fn value(&self) -> T;
+}
+
+impl RoopertGenericWidget<u8> for RoopertSlider {
+    fn value(&self) -> u8 {
+        self.value
+    }
+}
+
+fn widgets() -> Vec<Box<dyn RoopertWidget>> {
+    vec![
+        Box::new(RoopertButton { base: RoopertWidgetBase { id: 0 }, label: "ok".to_string() }),
+        Box::new(RoopertSlider { base: RoopertWidgetBase { id: 1 }, value: 3 }),
+    ]
+}
+
+#[test]
+fn downcast_ref_test() {
+    let mut widgets = widgets();
+    assert_eq!(widgets.iter().map(|widget| widget.draw()).collect::<Vec<_>>(), vec!["[ok]", "<3>"]);
+    assert!(widgets[0].is::<RoopertButton>());
+    assert!(!widgets[0].is::<RoopertSlider>());
+    assert_eq!(widgets[0].downcast_ref::<RoopertButton>().unwrap().label, "ok");
+    assert!(widgets[1].downcast_ref::<RoopertButton>().is_none());
+    widgets[1].downcast_mut::<RoopertSlider>().unwrap().value = 5;
+    assert!(widgets[0].downcast_mut::<RoopertSlider>().is_none());
+    assert_eq!(widgets[1].draw(), "<5>");
+    assert_eq!(widgets[1].downcast_ref::<RoopertSlider>().unwrap().id, 1);
+}
+
+#[test]
+fn downcast_box_test() {
+    let mut widgets = widgets();
+    let slider = widgets.pop().unwrap();
+    let slider = slider.downcast::<RoopertButton>().unwrap_err();
+    let slider = slider.downcast::<RoopertSlider>().ok().unwrap();
+    assert_eq!(*slider, RoopertSlider { base: RoopertWidgetBase { id: 1 }, value: 3 });
+    let generic: Box<dyn RoopertGenericWidget<u8>> = slider;
+    assert_eq!(generic.value(), 3);
+    assert_eq!(generic.downcast_ref::<RoopertSlider>().unwrap().value, 3);
+}
+
+#[roopert(object_trait, base = RoopertWidgetBase)]
+trait RoopertControl {
+    fn activate(&mut self);
+}
+
+#[roopert(object_trait, base = RoopertWidgetBase, parent = RoopertControl)]
+trait RoopertToggle {
+    fn is_on(&self) -> bool;
+}
+
+impl RoopertControl for RoopertWidgetBase {
+    fn activate(&mut self) {
+        self.id += 100;
+    }
+}
+
+impl RoopertControl for RoopertButton {
+    fn activate(&mut self) {
+        self.label.push('!');
+    }
+}
+
+impl RoopertToggle for RoopertButton {
+    fn is_on(&self) -> bool {
+        self.label.ends_with('!')
+    }
+}
+
+#[test]
+fn upcast_test() {
+    let mut controls: Vec<Box<dyn RoopertControl>> = vec![
+        Box::new(RoopertWidgetBase { id: 0 }),
+        Box::new(RoopertButton { base: RoopertWidgetBase { id: 1 }, label: "ok".to_string() }),
+    ];
+    assert_eq!(controls.iter().map(|control| control.as_base().id).collect::<Vec<_>>(), vec![0, 1]);
+    controls[1].as_base_mut().id = 2;
+    for control in controls.iter_mut() {
+        control.activate();
+    }
+    assert_eq!(controls[0].as_base().id, 100);
+    assert_eq!(controls[1].as_base(), &RoopertWidgetBase { id: 2 });
+    assert_eq!(controls[1].downcast_ref::<RoopertButton>().unwrap().label, "ok!");
+}
+
+#[test]
+fn dyn_parent_test() {
+    let mut toggle: Box<dyn RoopertToggle> = Box::new(RoopertButton { base: RoopertWidgetBase { id: 3 }, label: "on".to_string() });
+    assert!(!toggle.is_on());
+    toggle.as_dyn_parent_mut().activate();
+    assert!(toggle.is_on());
+    assert_eq!(toggle.as_dyn_parent().as_base().id, 3);
+    let control = toggle.into_dyn_parent();
+    assert_eq!(control.downcast_ref::<RoopertButton>().unwrap().label, "on!");
+}
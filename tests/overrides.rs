@@ -0,0 +1,135 @@
+//! Class method override tests
+
+use roopert::roopert;
+
+struct RoopertAnimal {
+    name: String,
+    sounds: usize,
+}
+
+#[roopert(class)]
+impl RoopertAnimal {
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            sounds: 0,
+        }
+    }
+
+    pub fn speak(&mut self) -> String {
+        self.sounds += 1;
+        format!("{} makes a sound", self.name)
+    }
+
+    pub fn describe(&self, prefix: &str) -> String {
+        format!("{}{}", prefix, self.name)
+    }
+
+    pub fn legs(&self) -> usize {
+        4
+    }
+}
+
+#[roopert(extends, RoopertAnimal)]
+struct RoopertDog {
+    animal: RoopertAnimal,
+    good: bool,
+}
+
+#[roopert(overrides, RoopertAnimal)]
+impl RoopertDog {
+    pub fn new(name: &str) -> Self {
+        Self {
+            animal: RoopertAnimal::new(name),
+            good: true,
+        }
+    }
+
+    #[roopert(override)]
+    pub fn speak(&mut self) -> String {
+        format!("{} (woof)", self.super_speak())
+    }
+
+    #[roopert(override)]
+    pub fn describe(&self, prefix: &str) -> String {
+        if self.good {
+            format!("{}good dog {}", prefix, self.name)
+        } else {
+            self.super_describe(prefix)
+        }
+    }
+}
+
+#[roopert(extends, RoopertAnimal)]
+struct RoopertBird {
+    animal: RoopertAnimal,
+}
+
+#[roopert(overrides, RoopertAnimal)]
+impl RoopertBird {
+    #[roopert(override)]
+    fn legs(&self) -> usize {
+        2
+    }
+}
+
+struct RoopertShape<T> {
+    size: T,
+}
+
+#[roopert(class, name = "ShapeBehaviour")]
+impl<T: Copy + core::ops::Add<Output = T>> RoopertShape<T> {
+    pub fn perimeter(&self) -> T {
+        self.size + self.size
+    }
+}
+
+#[roopert(extends, RoopertShape<u32>)]
+struct RoopertSquare {
+    shape: RoopertShape<u32>,
+}
+
+#[roopert(overrides, RoopertShape<u32>, class = ShapeBehaviour<u32>)]
+impl RoopertSquare {
+    #[roopert(override)]
+    fn perimeter(&self) -> u32 {
+        self.super_perimeter() * 2
+    }
+}
+
+fn speak_all(animals: &mut [&mut dyn RoopertAnimalClass]) -> Vec<String> {
+    animals.iter_mut().map(|animal| animal.speak()).collect()
+}
+
+#[test]
+fn override_dispatch_test() {
+    let mut animal = RoopertAnimal::new("Generic");
+    let mut dog = RoopertDog::new("Rex");
+    let mut bird = RoopertBird { animal: RoopertAnimal::new("Tweety") };
+    let spoken = speak_all(&mut [&mut animal, &mut dog, &mut bird]);
+    assert_eq!(spoken, vec!["Generic makes a sound", "Rex makes a sound (woof)", "Tweety makes a sound"]);
+    assert_eq!(dog.sounds, 1);
+    assert_eq!(bird.as_roopert_animal().sounds, 1);
+    let legs: Vec<usize> = [&animal as &dyn RoopertAnimalClass, &dog, &bird].iter().map(|animal| animal.legs()).collect();
+    assert_eq!(legs, vec![4, 4, 2]);
+}
+
+#[test]
+fn super_test() {
+    let mut dog = RoopertDog::new("Rex");
+    assert_eq!(RoopertAnimalClass::describe(&dog, "a "), "a good dog Rex");
+    assert_eq!(dog.super_describe("a "), "a Rex");
+    dog.good = false;
+    assert_eq!(dog.describe("a "), "a Rex");
+    assert_eq!(dog.super_speak(), "Rex makes a sound");
+    assert_eq!(dog.sounds, 1);
+}
+
+#[test]
+fn generic_class_test() {
+    let shape = RoopertShape { size: 3.5 };
+    assert_eq!(ShapeBehaviour::perimeter(&shape), 7.0);
+    let square = RoopertSquare { shape: RoopertShape { size: 3 } };
+    let shapes: [&dyn ShapeBehaviour<u32>; 2] = [&RoopertShape { size: 3 }, &square];
+    assert_eq!(shapes.iter().map(|shape| shape.perimeter()).collect::<Vec<_>>(), vec![6, 12]);
+}
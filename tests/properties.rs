@@ -0,0 +1,60 @@
+//! String-keyed property tests
+
+use roopert::{roopert, Properties, PropertyError};
+
+#[derive(Debug, Default, PartialEq)]
+struct RoopertOpaque(u8);
+
+#[roopert(properties)]
+#[roopert(accessors, get = all, set = Except(id))]
+#[derive(Default)]
+struct RoopertVehicle {
+    #[roopert(set, pre = assert!(x >= 0.0), post = self.changes += 1)]
+    speed: f64,
+    name: String,
+    id: u32,
+    opaque: RoopertOpaque,
+    #[roopert(skip)]
+    changes: usize,
+}
+
+#[roopert(properties)]
+#[roopert(accessors, set = all)]
+struct RoopertSlot<T> {
+    value: T,
+}
+
+#[test]
+fn properties_test() {
+    let mut vehicle = RoopertVehicle::default();
+    assert_eq!(vehicle.properties(), &["speed", "name", "id", "opaque"]);
+    vehicle.set_property("speed", "3.5").unwrap();
+    vehicle.set_property("name", "car").unwrap();
+    assert_eq!(vehicle.speed, 3.5);
+    assert_eq!(vehicle.changes, 1);
+    assert_eq!(vehicle.get_property("speed").unwrap(), "3.5");
+    assert_eq!(vehicle.get_property("name").unwrap(), "car");
+    assert_eq!(vehicle.get_property("id").unwrap(), "0");
+    let properties: &mut dyn Properties = &mut vehicle;
+    assert_eq!(properties.set_property("id", "1"), Err(PropertyError::ReadOnly { target: "RoopertVehicle", property: "id" }));
+    assert_eq!(properties.set_property("changes", "1"), Err(PropertyError::UnknownProperty { target: "RoopertVehicle", property: "changes".to_string() }));
+    assert_eq!(properties.get_property("opaque"), Err(PropertyError::Unsupported { target: "RoopertVehicle", property: "opaque" }));
+    assert_eq!(properties.set_property("opaque", "1"), Err(PropertyError::Unsupported { target: "RoopertVehicle", property: "opaque" }));
+    assert_eq!(properties.set_property("speed", "fast"), Err(PropertyError::Parse {
+        target: "RoopertVehicle",
+        property: "speed",
+        value: "fast".to_string(),
+        message: "fast".parse::<f64>().unwrap_err().to_string(),
+    }));
+    assert_eq!(vehicle.changes, 1);
+}
+
+#[test]
+fn generic_properties_test() {
+    let mut slot = RoopertSlot { value: 1u8 };
+    assert_eq!(slot.properties(), &["value"]);
+    assert_eq!(slot.get_property("value"), Err(PropertyError::WriteOnly { target: "RoopertSlot", property: "value" }));
+    // generic types are only converted when the struct's bounds allow it
+    assert_eq!(slot.set_property("value", "2"), Err(PropertyError::Unsupported { target: "RoopertSlot", property: "value" }));
+    assert_eq!(slot.value, 1);
+}
@@ -0,0 +1,143 @@
+//! Field reflection tests
+
+use roopert::{roopert, FieldInfo, FieldVisibility, Reflect, ReflectError};
+
+#[roopert(extends)]
+#[derive(Default)]
+struct RoopertWidgetBase {
+    pub visible: bool,
+}
+
+#[roopert(reflect)]
+#[roopert(extends, RoopertWidgetBase)]
+#[roopert(accessors, get = private, set = Except(base, id))]
+#[derive(Default)]
+struct RoopertButton {
+    base: RoopertWidgetBase,
+    #[roopert(set, post = self.changes += 1)]
+    label: String,
+    pub(crate) id: usize,
+    pub clicks: Vec<u32>,
+    #[roopert(skip)]
+    changes: usize,
+}
+
+#[roopert(reflect)]
+struct RoopertPair<T: Clone> {
+    pub first: T,
+    second: Option<T>,
+}
+
+#[test]
+fn fields_test() {
+    assert_eq!(RoopertButton::FIELDS.len(), 5);
+    assert_eq!(RoopertButton::FIELDS[0], FieldInfo {
+        name: "base",
+        type_name: "RoopertWidgetBase",
+        visibility: FieldVisibility::Private,
+        is_parent: true,
+        has_getter: true,
+        has_setter: false,
+    });
+    assert_eq!(RoopertButton::FIELDS[1].name, "label");
+    assert!(RoopertButton::FIELDS[1].has_getter && RoopertButton::FIELDS[1].has_setter);
+    assert_eq!(RoopertButton::FIELDS[2].visibility, FieldVisibility::Crate);
+    assert!(!RoopertButton::FIELDS[2].has_accessors());
+    assert_eq!(RoopertButton::FIELDS[3].type_name, "Vec<u32>");
+    assert_eq!(RoopertButton::FIELDS[3].visibility, FieldVisibility::Public);
+    assert!(!RoopertButton::FIELDS[4].has_accessors());
+    assert_eq!(RoopertPair::<u8>::FIELDS[1].type_name, "Option<T>");
+}
+
+#[test]
+fn get_set_field_test() {
+    let mut button = RoopertButton::default();
+    let reflected: &mut dyn Reflect = &mut button;
+    assert_eq!(reflected.fields().len(), 5);
+    reflected.set_field("label", Box::new("ok".to_string())).unwrap();
+    reflected.set_field("clicks", Box::new(vec![1u32])).unwrap();
+    assert_eq!(reflected.get_field("label").unwrap().downcast_ref::<String>().unwrap(), "ok");
+    // like for setting, fields which are not public and have no getter cannot be read
+    assert!(reflected.get_field("id").is_none());
+    assert!(reflected.get_field("changes").is_none());
+    assert!(reflected.get_field("base").is_some());
+    assert!(reflected.get_field("missing").is_none());
+    assert_eq!(reflected.set_field("label", Box::new(1u8)), Err(ReflectError::WrongType { target: "RoopertButton", field: "label", expected: "String" }));
+    assert_eq!(reflected.set_field("id", Box::new(1usize)), Err(ReflectError::ReadOnly { target: "RoopertButton", field: "id" }));
+    assert_eq!(reflected.set_field("missing", Box::new(1usize)), Err(ReflectError::UnknownField { target: "RoopertButton", field: "missing".to_string() }));
+    // the setter's post hook runs when setting through reflection
+    assert_eq!(button.changes, 1);
+    assert_eq!(button.clicks, vec![1]);
+    assert_eq!(button.get_label(), "ok");
+    assert_eq!(button.id, 0);
+    assert!(!button.visible);
+}
+
+#[test]
+fn generic_reflect_test() {
+    let mut pair = RoopertPair { first: 1u8, second: None };
+    pair.set_field("first", Box::new(2u8)).unwrap();
+    assert!(pair.set_field("second", Box::new(Some(3u8))).is_err());
+    assert_eq!(pair.get_field("first").unwrap().downcast_ref::<u8>(), Some(&2));
+    assert!(pair.get_field("second").is_none());
+    assert_eq!(pair.second, None);
+}
+
+#[roopert(reflect)]
+#[roopert(extends)]
+#[roopert(accessors, get = all, set = all)]
+#[derive(Default)]
+struct RoopertNode {
+    #[roopert(set, post = self.dirty = true)]
+    visible: bool,
+    #[roopert(skip)]
+    pub dirty: bool,
+}
+
+#[roopert(reflect, parents = nested)]
+#[roopert(extends, RoopertNode)]
+#[derive(Default)]
+struct RoopertPanel {
+    node: RoopertNode,
+    pub title: String,
+}
+
+#[roopert(reflect, parents = flatten)]
+#[roopert(extends, RoopertPanel)]
+#[derive(Default)]
+struct RoopertDialog {
+    panel: RoopertPanel,
+    pub modal: bool,
+}
+
+#[test]
+fn nested_parents_test() {
+    let mut panel = RoopertPanel::default();
+    let paths: Vec<String> = panel.all_fields().into_iter().map(|field| field.path).collect();
+    assert_eq!(paths, vec!["node", "title", "node.visible", "node.dirty"]);
+    assert!(panel.all_fields()[2].info.has_setter);
+    panel.set_field("node.visible", Box::new(true)).unwrap();
+    assert!(panel.visible);
+    assert!(panel.dirty);
+    assert_eq!(panel.get_field("node.visible").unwrap().downcast_ref::<bool>(), Some(&true));
+    assert!(panel.get_field("visible").is_none());
+    assert_eq!(panel.set_field("node.missing", Box::new(true)), Err(ReflectError::UnknownField { target: "RoopertNode", field: "missing".to_string() }));
+    assert_eq!(panel.set_field("visible", Box::new(true)), Err(ReflectError::UnknownField { target: "RoopertPanel", field: "visible".to_string() }));
+    assert_eq!(RoopertNode::default().all_fields().len(), 2);
+}
+
+#[test]
+fn flatten_parents_test() {
+    let mut dialog = RoopertDialog::default();
+    let paths: Vec<String> = dialog.all_fields().into_iter().map(|field| field.path).collect();
+    assert_eq!(paths, vec!["panel", "modal", "node", "title", "node.visible", "node.dirty"]);
+    dialog.set_field("title", Box::new("settings".to_string())).unwrap();
+    dialog.set_field("node.visible", Box::new(true)).unwrap();
+    dialog.set_field("modal", Box::new(true)).unwrap();
+    assert_eq!(dialog.title, "settings");
+    assert!(dialog.visible && dialog.dirty && dialog.modal);
+    let reflected: &dyn Reflect = &dialog;
+    assert_eq!(reflected.get_field("title").unwrap().downcast_ref::<String>().unwrap(), "settings");
+    assert!(reflected.get_field("node.dirty").is_some());
+    assert!(dialog.set_field("missing", Box::new(true)).is_err());
+}
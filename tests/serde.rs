@@ -0,0 +1,97 @@
+//! Serde integration tests
+
+use roopert::roopert;
+use serde::{Deserialize, Serialize};
+
+#[roopert(extends)]
+#[roopert(accessors, get = all, set = all, serde)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[serde(rename_all = "camelCase")]
+struct RoopertShape {
+    #[roopert(set, pre = assert!(x > 0, "sides must be positive"), post = self.validated += 1)]
+    side_count: u32,
+    #[serde(default)]
+    name: String,
+    #[roopert(skip)]
+    #[serde(skip)]
+    validated: usize,
+}
+
+#[roopert(extends, RoopertShape, serde)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct RoopertPolygon {
+    base: RoopertShape,
+    regular: bool,
+}
+
+// accessors above extends, so the parent is not flattened yet when accessors is expanded
+#[roopert(accessors, set = all, serde)]
+#[roopert(extends, RoopertShape, serde)]
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct RoopertTile {
+    #[roopert(set, skip)]
+    base: RoopertShape,
+    #[roopert(set, post = self.placed = true)]
+    id: u32,
+    #[serde(skip)]
+    #[roopert(set, skip)]
+    placed: bool,
+}
+
+#[roopert(accessors, set = all, serde)]
+#[derive(Deserialize)]
+#[serde(bound(deserialize = "T: Deserialize<'de> + Default"))]
+struct RoopertWrapper<T> {
+    #[roopert(set, post = self.changed = true)]
+    value: T,
+    #[serde(skip)]
+    #[roopert(set, skip)]
+    changed: bool,
+}
+
+#[test]
+fn serde_flatten_test() {
+    let polygon = RoopertPolygon {
+        base: RoopertShape { side_count: 3, name: "triangle".to_string(), validated: 0 },
+        regular: true,
+    };
+    let json = serde_json::to_string(&polygon).unwrap();
+    assert_eq!(json, r#"{"sideCount":3,"name":"triangle","regular":true}"#);
+    let loaded: RoopertPolygon = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.side_count, 3);
+    assert_eq!(loaded.get_name(), "triangle");
+    assert!(loaded.regular);
+}
+
+#[test]
+fn serde_flatten_below_accessors_test() {
+    let tile = RoopertTile {
+        base: RoopertShape { side_count: 4, name: "square".to_string(), validated: 0 },
+        id: 3,
+        placed: false,
+    };
+    let json = serde_json::to_string(&tile).unwrap();
+    assert_eq!(json, r#"{"sideCount":4,"name":"square","id":3}"#);
+    let loaded: RoopertTile = serde_json::from_str(&json).unwrap();
+    assert_eq!(loaded.side_count, 4);
+    assert_eq!(loaded.id, 3);
+    assert!(loaded.placed);
+}
+
+#[test]
+fn serde_setters_test() {
+    let shape: RoopertShape = serde_json::from_str(r#"{"sideCount":4}"#).unwrap();
+    assert_eq!(shape.side_count, 4);
+    assert_eq!(shape.name, "");
+    // side_count and name setters were both run
+    assert_eq!(shape.validated, 1);
+    let wrapper: RoopertWrapper<Vec<u8>> = serde_json::from_str(r#"{"value":[1,2]}"#).unwrap();
+    assert_eq!(wrapper.value, vec![1, 2]);
+    assert!(wrapper.changed);
+}
+
+#[test]
+fn serde_setter_hook_test() {
+    let error = serde_json::from_str::<RoopertShape>(r#"{"sideCount":0}"#).unwrap_err();
+    assert!(error.to_string().contains("sides must be positive"));
+}
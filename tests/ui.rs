@@ -0,0 +1,9 @@
+//! Compile-fail tests for roopert error messages
+//! Each case in `tests/ui` is expected to fail with the diagnostics in its `.stderr` file.
+//! Run with `TRYBUILD=overwrite` to update the `.stderr` files after an intended change to an error message.
+
+#[test]
+fn ui_test() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,18 @@
+use roopert::roopert;
+
+struct Shape {
+    name: String,
+}
+
+#[roopert(abstract, Shape)]
+trait ShapeMethods {
+    fn area(&self) -> f64;
+}
+
+#[roopert(extends, Shape)]
+struct Square {
+    shape: Shape,
+    side: f64,
+}
+
+fn main() {}
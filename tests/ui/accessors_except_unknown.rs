@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(accessors, get = All, set = Except(id))]
+struct Except {
+    x: u32,
+}
+
+fn main() {}
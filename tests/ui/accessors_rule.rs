@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(accessors, get = Sometimes)]
+struct Rule {
+    x: u32,
+}
+
+fn main() {}
@@ -0,0 +1,14 @@
+use roopert::roopert;
+
+struct Base {
+    id: usize,
+}
+
+#[roopert(extends, Base)]
+#[roopert(builder)]
+struct Derived {
+    base: Base,
+    name: String,
+}
+
+fn main() {}
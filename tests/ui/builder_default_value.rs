@@ -0,0 +1,9 @@
+use roopert::roopert;
+
+#[roopert(builder)]
+struct Defaults {
+    #[roopert(builder, default = )]
+    x: u32,
+}
+
+fn main() {}
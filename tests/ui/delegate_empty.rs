@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(delegate())]
+struct Empty {
+    inner: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(dispatch())]
+enum Empty {
+    A(String),
+}
+
+fn main() {}
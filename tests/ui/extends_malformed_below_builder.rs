@@ -0,0 +1,14 @@
+use roopert::roopert;
+
+struct Base {
+    id: usize,
+}
+
+#[roopert(builder)]
+#[roopert(extends, Base, 42)]
+struct Derived {
+    base: Base,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(extends, String)]
+struct NotAField {
+    x: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(extends, String, forward(Write))]
+struct Text {
+    text: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use roopert::roopert;
+
+#[roopert(accessors)]
+struct Maybe {
+    #[roopert(get, mutable = maybe)]
+    x: u32,
+}
+
+fn main() {}
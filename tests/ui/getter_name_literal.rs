@@ -0,0 +1,9 @@
+use roopert::roopert;
+
+#[roopert(accessors)]
+struct Named {
+    #[roopert(get, name = 42)]
+    x: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(new, default)]
+struct Counter {
+    count: usize,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use roopert::roopert;
+
+#[roopert(new)]
+struct Counter {
+    #[new(name = "create")]
+    count: usize,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use roopert::roopert;
+
+#[roopert(extends)]
+struct WidgetBase {
+    id: usize,
+}
+
+#[roopert(object_trait, base = WidgetBase)]
+trait Widget {}
+
+#[roopert(extends, WidgetBase)]
+struct Button {
+    base: WidgetBase,
+}
+
+impl Widget for Button {}
+
+// only direct parents implement Upcast, so the grandchild needs its own Upcast<WidgetBase>
+#[roopert(extends, Button)]
+struct IconButton {
+    button: Button,
+}
+
+impl Widget for IconButton {}
+
+fn main() {}
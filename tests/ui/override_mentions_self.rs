@@ -0,0 +1,29 @@
+use roopert::roopert;
+
+#[derive(Clone)]
+struct Animal {
+    name: String,
+}
+
+#[roopert(class)]
+impl Animal {
+    pub fn twin(&self) -> Self {
+        self.clone()
+    }
+}
+
+#[roopert(extends, Animal)]
+#[derive(Clone)]
+struct Dog {
+    animal: Animal,
+}
+
+#[roopert(overrides, Animal)]
+impl Dog {
+    #[roopert(override)]
+    pub fn twin(&self) -> Self {
+        self.clone()
+    }
+}
+
+fn main() {}
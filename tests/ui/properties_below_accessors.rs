@@ -0,0 +1,9 @@
+use roopert::roopert;
+
+#[roopert(accessors, get = All, set = All)]
+#[roopert(properties)]
+struct Setting {
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(properties)]
+enum NotAStruct {
+    A,
+}
+
+fn main() {}
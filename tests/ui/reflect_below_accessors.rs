@@ -0,0 +1,11 @@
+use roopert::roopert;
+
+#[roopert(accessors, get = All, set = All)]
+#[roopert(reflect)]
+struct Setting {
+    #[roopert(set, post = self.changes += 1)]
+    value: u32,
+    changes: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use roopert::roopert;
+
+#[roopert(accessors)]
+struct Named {
+    #[roopert(set, name = set_x)]
+    x: u32,
+}
+
+fn main() {}
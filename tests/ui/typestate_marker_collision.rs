@@ -0,0 +1,9 @@
+use roopert::roopert;
+
+#[roopert(builder, typestate)]
+struct Point {
+    foo_bar: i32,
+    foo__bar: i32,
+}
+
+fn main() {}
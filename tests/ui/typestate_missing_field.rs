@@ -0,0 +1,11 @@
+use roopert::roopert;
+
+#[roopert(builder, typestate)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn main() {
+    let _point = Point::builder().x(1).build();
+}
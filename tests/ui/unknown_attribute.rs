@@ -0,0 +1,8 @@
+use roopert::roopert;
+
+#[roopert(not_an_attribute)]
+struct Unknown {
+    x: u32,
+}
+
+fn main() {}